@@ -0,0 +1,565 @@
+use heck::{ToKebabCase, ToSnakeCase, ToTitleCase};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    parse::ParseStream, spanned::Spanned, Data, DeriveInput, Expr, ExprLit, ExprUnary, Fields,
+    GenericArgument, Ident, Lit, LitStr, PathArguments, Type, UnOp,
+};
+
+#[derive(Default)]
+struct FieldOptions {
+    label: Option<LitStr>,
+    skip: bool,
+    /// An `InputType` variant or `textarea`.
+    control: Option<Ident>,
+    required: bool,
+    trim: bool,
+    min: Option<Expr>,
+    max: Option<Expr>,
+    pattern: Option<LitStr>,
+    placeholder: Option<LitStr>,
+}
+
+const INPUT_TYPES: &[(&str, &str)] = &[
+    ("email", "Email"),
+    ("password", "Password"),
+    ("url", "Url"),
+    ("tel", "Tel"),
+    ("search", "Search"),
+    ("color", "Color"),
+    ("date", "Date"),
+    ("datetime_local", "DatetimeLocal"),
+    ("month", "Month"),
+    ("week", "Week"),
+    ("time", "Time"),
+    ("number", "Number"),
+    ("range", "Range"),
+    ("hidden", "Hidden"),
+    ("text", "Text"),
+];
+
+fn field_options(field: &syn::Field) -> syn::Result<FieldOptions> {
+    let mut options = FieldOptions::default();
+    for attribute in &field.attrs {
+        if !attribute.path().is_ident("form") {
+            continue;
+        }
+
+        attribute.parse_nested_meta(|meta| {
+            let Some(ident) = meta.path.get_ident() else {
+                return Err(meta.error("expected a form option"));
+            };
+            match ident.to_string().as_str() {
+                "label" => options.label = Some(meta.value()?.parse()?),
+                "skip" => options.skip = true,
+                "required" => options.required = true,
+                "trim" => options.trim = true,
+                "min" => options.min = Some(bound(meta.value()?)?),
+                "max" => options.max = Some(bound(meta.value()?)?),
+                "pattern" => options.pattern = Some(meta.value()?.parse()?),
+                "placeholder" => options.placeholder = Some(meta.value()?.parse()?),
+                "textarea" => options.control = Some(ident.clone()),
+                name => match INPUT_TYPES.iter().find(|(option, _)| *option == name) {
+                    Some((_, variant)) => options.control = Some(format_ident!("{variant}")),
+                    None => return Err(meta.error(format!("unknown form option `{name}`"))),
+                },
+            }
+            Ok(())
+        })?;
+    }
+    Ok(options)
+}
+
+/// Parses a `min` or `max` value, a literal or a negative number.
+fn bound(input: ParseStream) -> syn::Result<Expr> {
+    let expr = input.parse()?;
+    match &expr {
+        Expr::Lit(_) => Ok(expr),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr: operand,
+            ..
+        }) if is_number(operand) => Ok(expr),
+        _ => Err(syn::Error::new(expr.span(), "expected a literal")),
+    }
+}
+
+/// Whether a `min` or `max` value is a number, which is then checked when parsing.
+fn is_number(expr: &Expr) -> bool {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(_) | Lit::Float(_),
+            ..
+        }) => true,
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => is_number(expr),
+        _ => false,
+    }
+}
+
+/// Rejects a numeric `min` or `max` that an integer field can't hold, which would otherwise
+/// wrap or truncate when converted with `as`, e.g. `min = -1` on a `u32`.
+fn check_bound(bound: &Expr, ty: &Type) -> syn::Result<()> {
+    let (signed, bits) = match ty {
+        Type::Path(path) => match path.path.get_ident().map(Ident::to_string).as_deref() {
+            Some("i8") => (true, 8),
+            Some("i16") => (true, 16),
+            Some("i32") => (true, 32),
+            Some("i64" | "isize") => (true, 64),
+            Some("i128") => (true, 128),
+            Some("u8") => (false, 8),
+            Some("u16") => (false, 16),
+            Some("u32") => (false, 32),
+            Some("u64" | "usize") => (false, 64),
+            Some("u128") => (false, 128),
+            _ => return Ok(()),
+        },
+        _ => return Ok(()),
+    };
+
+    let (negative, literal) = match bound {
+        Expr::Unary(ExprUnary { expr, .. }) => (true, &**expr),
+        expr => (false, expr),
+    };
+    let magnitude = match literal {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(int), ..
+        }) => int.base10_parse::<u128>().ok(),
+        _ => None,
+    };
+    let fits = magnitude.is_some_and(|magnitude| match (signed, negative) {
+        (false, true) => magnitude == 0,
+        (false, false) => bits == 128 || magnitude < 1 << bits,
+        (true, true) => magnitude <= 1 << (bits - 1),
+        (true, false) => magnitude < 1 << (bits - 1),
+    });
+    if fits {
+        Ok(())
+    } else {
+        Err(syn::Error::new(
+            bound.span(),
+            format!("`{}` doesn't fit in `{}`", quote!(#bound), quote!(#ty)),
+        ))
+    }
+}
+
+/// Returns `T` for `Option<T>`.
+fn optional(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    match &segment.arguments {
+        PathArguments::AngleBracketed(arguments) => match arguments.args.first()? {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// The input type for types with an obvious control, `None` for other types which use a
+/// `select`.
+fn default_control(ty: &Type) -> Option<&'static str> {
+    let ident = match ty {
+        Type::Reference(reference) => return default_control(&reference.elem),
+        Type::Path(path) => path.path.segments.last()?.ident.to_string(),
+        _ => return None,
+    };
+    match ident.as_str() {
+        "String" | "str" | "char" | "Cow" => Some("Text"),
+        "bool" => Some("Checkbox"),
+        "Date" => Some("Date"),
+        "u8" | "u16" | "u32" | "u64" | "u128" | "usize" | "i8" | "i16" | "i32" | "i64" | "i128"
+        | "isize" | "f32" | "f64" => Some("Number"),
+        _ => None,
+    }
+}
+
+fn is_float(ty: &Type) -> bool {
+    matches!(ty, Type::Path(path) if path.path.is_ident("f32") || path.path.is_ident("f64"))
+}
+
+pub(crate) fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new(
+                    input.span(),
+                    "`Form` can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new(
+                input.span(),
+                "`Form` can only be derived for structs",
+            ))
+        }
+    };
+
+    let prefix = input.ident.to_string().to_kebab_case();
+    let mut controls = Vec::new();
+    let mut parsed = Vec::new();
+    let mut constructed = Vec::new();
+    for (index, field) in fields.iter().enumerate() {
+        let options = field_options(field)?;
+        let ident = field.ident.as_ref().expect("named field");
+        if options.skip {
+            constructed.push(quote!(#ident: ::std::default::Default::default()));
+            continue;
+        }
+
+        let name = ident.to_string().trim_start_matches("r#").to_string();
+        let id = format!("{prefix}-{}", name.to_kebab_case());
+        let label = match &options.label {
+            Some(label) => label.value(),
+            None => name.to_title_case(),
+        };
+
+        let (ty, value) = match optional(&field.ty) {
+            Some(ty) => (ty, quote!(value.and_then(|value| value.#ident.as_ref()))),
+            None => (&field.ty, quote!(value.map(|value| &value.#ident))),
+        };
+
+        let mut attributes = Vec::new();
+        if options.required {
+            attributes.push(quote!(.attribute("required", "required")));
+        }
+        if let Some(min) = &options.min {
+            attributes.push(quote!(.attribute("min", #min)));
+        }
+        if let Some(max) = &options.max {
+            attributes.push(quote!(.attribute("max", #max)));
+        }
+        if let Some(pattern) = &options.pattern {
+            attributes.push(quote!(.attribute("pattern", #pattern)));
+        }
+        if let Some(placeholder) = &options.placeholder {
+            attributes.push(quote!(.attribute("placeholder", #placeholder)));
+        }
+
+        let control = options
+            .control
+            .as_ref()
+            .map(Ident::to_string)
+            .or_else(|| default_control(ty).map(str::to_string));
+
+        let variable = format_ident!("field_{index}");
+        let is_optional = optional(&field.ty).is_some();
+        let parse = match (control.as_deref(), is_optional) {
+            (Some("Checkbox"), false) => quote!(::std::option::Option::Some(state.checkbox(#name))),
+            (Some("Checkbox"), true) => quote! {
+                ::std::option::Option::Some(::std::option::Option::Some(state.checkbox(#name)))
+            },
+            (None, false) => quote!(state.select::<#ty>(#name)),
+            (None, true) if options.required => {
+                quote!(state.select::<#ty>(#name).map(::std::option::Option::Some))
+            }
+            (None, true) => quote!(state.optional_select::<#ty>(#name)),
+            (Some(_), true) if options.required => {
+                quote!(state.required::<#ty>(#name).map(::std::option::Option::Some))
+            }
+            (Some(_), true) => quote!(state.optional::<#ty>(#name)),
+            (Some(_), false) if options.required => quote!(state.required::<#ty>(#name)),
+            (Some(_), false) => quote!(state.field::<#ty>(#name)),
+        };
+        if options.trim {
+            parsed.push(quote!(state.trim(#name);));
+        }
+        parsed.push(quote!(let #variable = #parse;));
+        let checked = if is_optional {
+            quote!(#variable.as_ref().and_then(|value| value.as_ref()))
+        } else {
+            quote!(#variable.as_ref())
+        };
+        let bounds = [
+            (&options.min, quote!(<), "at least"),
+            (&options.max, quote!(>), "at most"),
+        ];
+        for (bound, operator, message) in bounds {
+            let Some(bound) = bound.as_ref().filter(|bound| is_number(bound)) else {
+                continue;
+            };
+            if default_control(ty) != Some("Number") {
+                continue;
+            }
+            check_bound(bound, ty)?;
+            let message = format!("Must be {message} {{}}.");
+            parsed.push(quote! {
+                if let ::std::option::Option::Some(value) = #checked {
+                    if *value #operator (#bound as #ty) {
+                        state.error(#name, ::std::format!(#message, #bound));
+                    }
+                }
+            });
+        }
+        constructed.push(quote!(#ident: #variable?));
+        let control = match control.as_deref() {
+            Some("textarea") => quote! {
+                let control = ::html_builder::prelude::textarea(#id, #name) #(#attributes)*;
+                let control = match #value {
+                    ::std::option::Option::Some(value) => control.text(value),
+                    ::std::option::Option::None => control,
+                };
+                ::html_builder::prelude::div()
+                    .child(::html_builder::prelude::label(#id).text(#label))
+                    .child(control)
+            },
+            Some("Checkbox") => quote! {
+                let control =
+                    ::html_builder::prelude::checkbox(#name, #value.is_some_and(|value| *value))
+                        .id(#id)
+                        .value("true")
+                    #(#attributes)*;
+                ::html_builder::prelude::div()
+                    .child(control)
+                    .child(::html_builder::prelude::label(#id).text(#label))
+            },
+            Some(input_type) => {
+                let input_type = format_ident!("{input_type}");
+                let step = is_float(ty).then(|| quote!(.step("any")));
+                // Passwords are never sent back to the browser.
+                let value = if input_type == "Password" {
+                    quote!(::std::option::Option::None::<&str>)
+                } else {
+                    value
+                };
+                let control = quote! {
+                    let control = ::html_builder::prelude::input(
+                        ::html_builder::prelude::InputType::#input_type,
+                        #name,
+                    )
+                    .id(#id)
+                        #step
+                        #(#attributes)*;
+                    let control = match #value {
+                        ::std::option::Option::Some(value) => control.value(value),
+                        ::std::option::Option::None => control,
+                    };
+                };
+                if input_type == "Hidden" {
+                    quote!(#control control)
+                } else {
+                    quote! {
+                        #control
+                        ::html_builder::prelude::div()
+                            .child(::html_builder::prelude::label(#id).text(#label))
+                            .child(control)
+                    }
+                }
+            }
+            None => {
+                let placeholder = optional(&field.ty)
+                    .is_some()
+                    .then(|| quote!(.placeholder("")));
+                quote! {
+                    let control = ::html_builder::prelude::select(#id, #name)
+                        #(#attributes)*
+                        .options_selected(
+                            <#ty as ::html_builder::forms::SelectOptions>::options(),
+                            #value
+                                .map(::html_builder::prelude::OptionValue::value)
+                                .unwrap_or_default(),
+                        )
+                        #placeholder;
+                    ::html_builder::prelude::div()
+                        .child(::html_builder::prelude::label(#id).text(#label))
+                        .child(control)
+                }
+            }
+        };
+        controls.push(quote!(::html_builder::Node::from({ #control })));
+    }
+
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::html_builder::forms::FormFields for #name #type_generics #where_clause {
+            fn fields(value: ::std::option::Option<&Self>) -> ::std::vec::Vec<::html_builder::Node> {
+                use ::html_builder::prelude::{Children as _, Element as _};
+                ::std::vec![#(#controls),*]
+            }
+
+            fn from_state(state: &mut ::html_builder::forms::FormState) -> ::std::option::Option<Self> {
+                #(#parsed)*
+                ::std::option::Option::Some(Self { #(#constructed),* })
+            }
+        }
+    })
+}
+
+#[derive(Default)]
+struct VariantOptions {
+    label: Option<LitStr>,
+    value: Option<LitStr>,
+}
+
+pub(crate) fn derive_select_options(input: DeriveInput) -> syn::Result<TokenStream> {
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new(
+            input.span(),
+            "`SelectOptions` can only be derived for enums",
+        ));
+    };
+
+    let mut options = Vec::new();
+    let mut texts = Vec::new();
+    let mut values = Vec::new();
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new(
+                variant.span(),
+                "`SelectOptions` variants cannot have fields",
+            ));
+        }
+
+        let mut variant_options = VariantOptions::default();
+        for attribute in &variant.attrs {
+            if !attribute.path().is_ident("form") {
+                continue;
+            }
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("label") {
+                    variant_options.label = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("value") {
+                    variant_options.value = Some(meta.value()?.parse()?);
+                } else {
+                    return Err(meta.error("expected `label` or `value`"));
+                }
+                Ok(())
+            })?;
+        }
+
+        let ident = &variant.ident;
+        let name = ident.to_string();
+        let value = variant_options
+            .value
+            .map(|value| value.value())
+            .unwrap_or_else(|| name.to_snake_case());
+        let label = variant_options
+            .label
+            .map(|label| label.value())
+            .unwrap_or_else(|| name.to_title_case());
+        options.push(quote!(Self::#ident));
+        texts.push(quote!(Self::#ident => #label));
+        values.push(quote!(Self::#ident => #value));
+    }
+
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::html_builder::prelude::OptionValue for #name #type_generics #where_clause {
+            fn text(&self) -> ::std::string::String {
+                match self {
+                    #(#texts,)*
+                }
+                .to_string()
+            }
+
+            fn value(&self) -> ::std::string::String {
+                match self {
+                    #(#values,)*
+                }
+                .to_string()
+            }
+        }
+
+        impl #impl_generics ::html_builder::forms::SelectOptions for #name #type_generics #where_clause {
+            fn options() -> ::std::vec::Vec<Self> {
+                ::std::vec![#(#options),*]
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse::Parser;
+
+    fn field(tokens: TokenStream) -> syn::Field {
+        syn::Field::parse_named.parse2(tokens).unwrap()
+    }
+
+    #[test]
+    fn bounds_accept_negative_numbers() {
+        let options = field_options(&field(quote!(#[form(min = -5, max = 2.5)] x: f64))).unwrap();
+        let tokens = |bound: Option<Expr>| bound.map(|bound| quote!(#bound).to_string());
+        assert_eq!(tokens(options.min.clone()).as_deref(), Some("- 5"));
+        assert_eq!(tokens(options.max).as_deref(), Some("2.5"));
+        assert!(options.min.as_ref().is_some_and(is_number));
+
+        let options = field_options(&field(quote!(#[form(min = "2024-01-01")] x: String))).unwrap();
+        assert!(!options.min.as_ref().is_some_and(is_number));
+    }
+
+    #[test]
+    fn bounds_must_fit_integer_fields() {
+        let check = |bound: TokenStream, ty: TokenStream| {
+            let bound = syn::parse2::<Expr>(bound).unwrap();
+            let ty = syn::parse2::<Type>(ty).unwrap();
+            check_bound(&bound, &ty).map_err(|error| error.to_string())
+        };
+        assert_eq!(
+            check(quote!(-5), quote!(u32)),
+            Err("`- 5` doesn't fit in `u32`".into())
+        );
+        assert_eq!(
+            check(quote!(256), quote!(u8)),
+            Err("`256` doesn't fit in `u8`".into())
+        );
+        assert_eq!(
+            check(quote!(2.5), quote!(u8)),
+            Err("`2.5` doesn't fit in `u8`".into())
+        );
+        assert_eq!(
+            check(quote!(128), quote!(i8)),
+            Err("`128` doesn't fit in `i8`".into())
+        );
+        assert_eq!(check(quote!(255), quote!(u8)), Ok(()));
+        assert_eq!(check(quote!(-0), quote!(u8)), Ok(()));
+        assert_eq!(check(quote!(-128), quote!(i8)), Ok(()));
+        assert_eq!(check(quote!(127), quote!(i8)), Ok(()));
+        assert_eq!(check(quote!(-5), quote!(f32)), Ok(()));
+        assert_eq!(
+            check(
+                quote!(340282366920938463463374607431768211455),
+                quote!(u128)
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            check(
+                quote!(-170141183460469231731687303715884105728),
+                quote!(i128)
+            ),
+            Ok(())
+        );
+
+        let input = syn::parse2(quote! {
+            struct Order {
+                #[form(min = -1)]
+                quantity: Option<u32>,
+            }
+        })
+        .unwrap();
+        let error = derive(input).err().unwrap();
+        assert_eq!(error.to_string(), "`- 1` doesn't fit in `u32`");
+    }
+
+    #[test]
+    fn bounds_reject_expressions() {
+        let error = field_options(&field(quote!(#[form(min = 1 + 2)] x: i32)))
+            .err()
+            .unwrap();
+        assert_eq!(error.to_string(), "expected a literal");
+    }
+}
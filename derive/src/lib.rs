@@ -0,0 +1,59 @@
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+mod form;
+mod table;
+
+/// Implements `html_builder::table::TableRow`, with a column for every named field.
+///
+/// Cells use the field's `Display` implementation unless overridden, and field attributes
+/// configure the columns:
+/// - `#[table(header = "...")]` sets the header, which defaults to the field name in title case
+/// - `#[table(skip)]` leaves the field out
+/// - `#[table(format = path::to::function)]` builds the cell with a function taking a reference
+///   to the field and returning anything that converts into a `Node`
+/// - `#[table(sortable)]` makes the column sortable by the field name
+/// - `#[table(class = "...")]` sets a class on the column's `col`
+#[proc_macro_derive(Table, attributes(table))]
+pub fn derive_table(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    table::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Implements `html_builder::forms::FormFields`, with a labelled control for every named field.
+///
+/// Controls are picked from the field type: text inputs for strings, checkboxes for `bool`,
+/// number inputs for numbers and a `select` for any other type, which must implement
+/// `SelectOptions`. `Option` fields use the control of their inner type and may be left empty.
+/// `from_state` parses the submitted fields with `FromStr`, checking `required` and numeric
+/// `min` and `max`, and skipped fields use `Default`. Field attributes configure the controls:
+/// - `#[form(email)]`, `#[form(password)]`, `#[form(date)]` etc. use that input type instead,
+///   and `#[form(textarea)]` uses a `textarea`
+/// - `#[form(label = "...")]` sets the label, which defaults to the field name in title case
+/// - `#[form(required)]`, `#[form(min = ...)]`, `#[form(max = ...)]`,
+///   `#[form(pattern = "...")]` and `#[form(placeholder = "...")]` set those attributes
+/// - `#[form(trim)]` removes whitespace around the submitted value before parsing it, values
+///   are otherwise parsed as submitted
+/// - `#[form(skip)]` leaves the field out
+#[proc_macro_derive(Form, attributes(form))]
+pub fn derive_form(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    form::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Implements `html_builder::forms::SelectOptions` and `OptionValue` for an enum of unit
+/// variants.
+///
+/// Values default to the variant name in snake case and texts to the variant name in title
+/// case, `#[form(value = "...")]` and `#[form(label = "...")]` override them.
+#[proc_macro_derive(SelectOptions, attributes(form))]
+pub fn derive_select_options(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    form::derive_select_options(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
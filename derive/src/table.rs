@@ -0,0 +1,109 @@
+use heck::ToTitleCase;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{spanned::Spanned, Data, DeriveInput, Fields, LitStr, Path};
+
+#[derive(Default)]
+struct ColumnOptions {
+    header: Option<LitStr>,
+    skip: bool,
+    format: Option<Path>,
+    sortable: bool,
+    class: Option<LitStr>,
+}
+
+fn column_options(field: &syn::Field) -> syn::Result<ColumnOptions> {
+    let mut options = ColumnOptions::default();
+    for attribute in &field.attrs {
+        if !attribute.path().is_ident("table") {
+            continue;
+        }
+
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("header") {
+                options.header = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("skip") {
+                options.skip = true;
+            } else if meta.path.is_ident("format") {
+                options.format = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("sortable") {
+                options.sortable = true;
+            } else if meta.path.is_ident("class") {
+                options.class = Some(meta.value()?.parse()?);
+            } else {
+                return Err(
+                    meta.error("expected `header`, `skip`, `format`, `sortable` or `class`")
+                );
+            }
+            Ok(())
+        })?;
+    }
+    Ok(options)
+}
+
+pub(crate) fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new(
+                    input.span(),
+                    "`Table` can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new(
+                input.span(),
+                "`Table` can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut columns = Vec::new();
+    for field in fields {
+        let options = column_options(field)?;
+        if options.skip {
+            continue;
+        }
+
+        let ident = field.ident.as_ref().expect("named field");
+        let name = ident.to_string().trim_start_matches("r#").to_string();
+        let header = match options.header {
+            Some(header) => header.value(),
+            None => name.to_title_case(),
+        };
+        let cell = match options.format {
+            Some(format) => quote!(::html_builder::Node::from(#format(&row.#ident))),
+            None => quote!(::html_builder::Node::Text(row.#ident.to_string())),
+        };
+
+        let mut column = quote! {
+            ::html_builder::table::column(#header, |row: &R| {
+                let row: &Self = ::std::borrow::Borrow::borrow(row);
+                #cell
+            })
+        };
+        if options.sortable {
+            column = quote!(#column.sortable(#name));
+        }
+        if let Some(class) = options.class {
+            column = quote!(#column.class(#class));
+        }
+        columns.push(column);
+    }
+
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::html_builder::table::TableRow for #name #type_generics #where_clause {
+            fn columns<'a, R>() -> ::std::vec::Vec<::html_builder::table::Column<'a, R>>
+            where
+                R: ::std::borrow::Borrow<Self> + 'a,
+                Self: 'a,
+            {
+                ::std::vec![#(#columns),*]
+            }
+        }
+    })
+}
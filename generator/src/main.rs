@@ -2,7 +2,7 @@
 
 use heck::{ToSnakeCase, ToUpperCamelCase};
 use itertools::Itertools;
-use proc_macro2::Ident;
+use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
 use serde::{de::IntoDeserializer, Deserialize};
 use serde_json::Value;
@@ -21,16 +21,21 @@ impl Description {
     }
 }
 
-#[derive(Deserialize)]
-struct Reference {
-    name: String,
-    url: String,
-}
-
 #[derive(Deserialize)]
 struct Attribute {
     name: String,
     description: Option<Description>,
+    #[serde(rename = "valueSet")]
+    value_set: Option<String>,
+}
+
+impl Attribute {
+    fn doc(&self) -> &str {
+        self.description
+            .as_ref()
+            .and_then(|description| description.as_str())
+            .unwrap_or_default()
+    }
 }
 
 #[derive(Deserialize)]
@@ -38,7 +43,6 @@ struct Tag {
     name: String,
     description: Description,
     attributes: Vec<Attribute>,
-    references: Vec<Reference>,
     void: Option<bool>,
 }
 
@@ -56,7 +60,125 @@ impl Tag {
     }
 }
 
+/// Global attributes missing from the data source.
+const EXTRA_GLOBAL_ATTRIBUTES: &[(&str, &str)] = &[
+    ("enterkeyhint", "Hints what action label (or icon) to present for the enter key on virtual keyboards."),
+    ("inert", "Makes the browser disregard user input events for the element, including focus events and events from assistive technologies."),
+    ("nonce", "A cryptographic nonce used by Content Security Policy to determine whether a given fetch will be allowed to proceed."),
+    ("popover", "Designates an element as a popover element."),
+];
+
+/// Event handler attributes missing from the data source.
+const EXTRA_EVENTS: &[&str] = &[
+    "animationend",
+    "animationstart",
+    "auxclick",
+    "beforeinput",
+    "beforetoggle",
+    "copy",
+    "cut",
+    "paste",
+    "toggle",
+    "touchcancel",
+    "touchend",
+    "touchmove",
+    "touchstart",
+    "transitionend",
+    "wheel",
+];
+
+fn event_handler(attribute: &Attribute) -> TokenStream {
+    let name = attribute.name.as_str();
+    let doc = match attribute.doc() {
+        "" => format!(
+            "Sets the script run for the `{}` event.",
+            &name["on".len()..]
+        ),
+        doc => doc.to_string(),
+    };
+    let method_name = format_ident!("{}", name);
+
+    quote! {
+        #[doc = #doc]
+        fn #method_name(self, script: impl std::fmt::Display) -> Self {
+            self.attribute(#name, script)
+        }
+    }
+}
+
+/// Emits a typed method for the `Element` trait, skipping attributes that have dedicated APIs.
+fn global_attribute(attribute: &Attribute) -> Option<TokenStream> {
+    let name = attribute.name.as_str();
+    if matches!(name, "class" | "id" | "style" | "role") || name.starts_with("aria-") {
+        return None;
+    }
+
+    let doc = attribute.doc();
+    let method_name = format_ident!("{}", name.to_snake_case());
+    let value_type = match name {
+        "dir" => quote! { crate::Dir },
+        "popover" => quote! { crate::Popover },
+        "enterkeyhint" => quote! { crate::EnterKeyHint },
+        "contenteditable" => quote! { crate::ContentEditable },
+        "autocapitalize" => quote! { crate::Autocapitalize },
+        "inputmode" => quote! { crate::InputMode },
+        "tabindex" => quote! { i32 },
+        "draggable" | "spellcheck" => quote! { bool },
+        "translate" => {
+            return Some(quote! {
+                #[doc = #doc]
+                fn #method_name(self, value: bool) -> Self {
+                    self.attribute(#name, if value { "yes" } else { "no" })
+                }
+            })
+        }
+        _ if matches!(attribute.value_set.as_deref(), Some("v")) || name == "inert" => {
+            let set_method_name = format_ident!("set_{}", name.to_snake_case());
+            return Some(quote! {
+                #[doc = #doc]
+                fn #method_name(self) -> Self {
+                    self.attribute(#name, #name)
+                }
+
+                fn #set_method_name(self, value: bool) -> Self {
+                    if value {
+                        self.#method_name()
+                    } else {
+                        self.remove_attribute(#name)
+                    }
+                }
+            });
+        }
+        _ => quote! { impl std::fmt::Display },
+    };
+
+    Some(quote! {
+        #[doc = #doc]
+        fn #method_name(self, value: #value_type) -> Self {
+            self.attribute(#name, value)
+        }
+    })
+}
+
 fn main() {
+    match std::env::args().nth(1).as_deref() {
+        Some("html") => html(),
+        Some("svg") => svg(),
+        Some("mathml") => mathml(),
+        Some("aria") => aria(),
+        None => {
+            html();
+            svg();
+            mathml();
+            aria();
+        }
+        Some(target) => {
+            panic!("unknown target `{target}`, expected `html`, `svg`, `mathml` or `aria`")
+        }
+    }
+}
+
+fn html() {
     let data = std::fs::read_to_string(
         "node_modules/@vscode/web-custom-data/data/browsers.html-data.json",
     )
@@ -64,6 +186,71 @@ fn main() {
 
     let data = serde_json::Value::from_str(&data).unwrap();
 
+    let mut global_attributes =
+        Vec::<Attribute>::deserialize(data["globalAttributes"].clone().into_deserializer())
+            .unwrap();
+    let extra_events = EXTRA_EVENTS
+        .iter()
+        .map(|event| (format!("on{event}"), String::new()));
+    let extra_attributes = EXTRA_GLOBAL_ATTRIBUTES
+        .iter()
+        .map(|(name, doc)| (name.to_string(), doc.to_string()));
+    for (name, doc) in extra_attributes.chain(extra_events) {
+        if !global_attributes
+            .iter()
+            .any(|attribute| attribute.name == name)
+        {
+            global_attributes.push(Attribute {
+                name,
+                description: Some(Description(Value::from(doc))),
+                value_set: None,
+            });
+        }
+    }
+    global_attributes.sort_by(|a, b| a.name.cmp(&b.name));
+    let global_attributes = global_attributes
+        .into_iter()
+        .unique_by(|attribute| attribute.name.clone())
+        .collect::<Vec<_>>();
+
+    let (events, global_attributes): (Vec<_>, Vec<_>) = global_attributes
+        .iter()
+        .partition(|attribute| attribute.name.starts_with("on"));
+
+    let event_handlers = events.iter().map(|attribute| event_handler(attribute));
+    let global_attributes = global_attributes
+        .iter()
+        .filter_map(|attribute| global_attribute(attribute));
+
+    let event_variants = events.iter().map(|attribute| {
+        let event = &attribute.name["on".len()..];
+        let variant = format_ident!("{}", event.to_upper_camel_case());
+        quote! { #variant => #event, }
+    });
+
+    let global_attributes = quote! {
+        use crate::macros::keywords;
+
+        keywords!(Event { #(#event_variants)* });
+
+        /// Expands to a typed method on the `Element` trait for every global attribute.
+        macro_rules! global_attributes {
+            () => {
+                #(#global_attributes)*
+
+                #(#event_handlers)*
+            };
+        }
+
+        pub(crate) use global_attributes;
+    };
+
+    std::fs::write(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../src/global_attributes.rs"),
+        global_attributes.to_string(),
+    )
+    .unwrap();
+
     let data = Vec::<Tag>::deserialize(data["tags"].clone().into_deserializer()).unwrap();
 
     let data = data.into_iter().map(|value| {
@@ -83,11 +270,7 @@ fn main() {
             .unique_by(|attribute| attribute.name.clone())
             .map(|attribute| {
                 let attribute_name = &attribute.name;
-                let doc = attribute
-                    .description
-                    .as_ref()
-                    .and_then(|description| description.as_str())
-                    .unwrap_or_default();
+                let doc = attribute.doc();
 
                 let method_name = format_ident!(
                     "{}",
@@ -124,3 +307,209 @@ fn main() {
     )
     .unwrap();
 }
+
+/// Elements of a foreign language, SVG or MathML, embedded in HTML.
+#[derive(Deserialize)]
+struct ForeignData {
+    tags: Vec<Tag>,
+    #[serde(rename = "presentationAttributes")]
+    presentation_attributes: Vec<Attribute>,
+}
+
+fn foreign_method_name(attribute: &Attribute) -> Ident {
+    match attribute.name.as_str() {
+        "in" | "type" => format_ident!("r#{}", attribute.name),
+        name => format_ident!("{}", name.to_snake_case()),
+    }
+}
+
+fn svg() {
+    foreign("svg", "svg", "SVG_NAMESPACE", "http://www.w3.org/2000/svg");
+}
+
+fn mathml() {
+    foreign(
+        "mathml",
+        "math",
+        "MATHML_NAMESPACE",
+        "http://www.w3.org/1998/Math/MathML",
+    );
+}
+
+/// Generates `src/{module}.rs` from `data/{module}.json`, with `xmlns` set on the `root` element.
+fn foreign(module: &str, root: &str, namespace_name: &str, namespace: &str) {
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    let data = std::fs::read_to_string(format!("{manifest_dir}/data/{module}.json")).unwrap();
+    let data: ForeignData = serde_json::from_str(&data).unwrap();
+    let namespace_name = format_ident!("{}", namespace_name);
+
+    let presentation_attributes = data.presentation_attributes.iter().map(|attribute| {
+        let name = &attribute.name;
+        let doc = attribute.doc();
+        let method_name = foreign_method_name(attribute);
+        quote! {
+            #[doc = #doc]
+            fn #method_name(self, value: impl std::fmt::Display) -> Self {
+                self.attribute(#name, value)
+            }
+        }
+    });
+
+    let elements = data.tags.iter().map(|tag| {
+        let name = tag.name();
+        // Tag names are kept as strings, since some are keywords or camelCase.
+        let tag_name = &tag.name;
+        let doc = tag.description.as_str().unwrap_or_default();
+        let constructor = match tag_name.as_str() {
+            "use" => format_ident!("r#use"),
+            tag_name => format_ident!("{}", tag_name.to_snake_case()),
+        };
+        let body = if tag_name == root {
+            quote! { #name::new_empty().attribute("xmlns", #namespace_name) }
+        } else {
+            quote! { #name::new_empty() }
+        };
+
+        let attributes = tag
+            .attributes
+            .iter()
+            .unique_by(|attribute| attribute.name.clone())
+            .map(|attribute| {
+                let attribute_name = &attribute.name;
+                let doc = attribute.doc();
+                let method_name = foreign_method_name(attribute);
+                quote! {
+                    element_attribute!(#name, #method_name, #attribute_name, #doc);
+                }
+            });
+
+        quote! {
+            element_struct!(#name, #tag_name, #doc);
+            #(#attributes)*
+            impl Presentation for #name {}
+
+            pub fn #constructor() -> #name {
+                #body
+            }
+        }
+    });
+
+    let data = quote! {
+        use crate::{element_struct, element_attribute};
+        use crate::node::prelude::*;
+
+        pub const #namespace_name: &str = #namespace;
+
+        /// Presentation attributes, which every element accepts.
+        pub trait Presentation: Element {
+            #(#presentation_attributes)*
+        }
+
+        #(#elements)*
+    };
+
+    std::fs::write(
+        format!("{manifest_dir}/../src/{module}.rs"),
+        data.to_string(),
+    )
+    .unwrap();
+}
+
+/// A WAI-ARIA state or property.
+#[derive(Deserialize)]
+struct AriaAttribute {
+    name: String,
+    description: String,
+    /// The WAI-ARIA value type, e.g. `boolean`, `tristate` or `token`.
+    #[serde(rename = "type")]
+    value_type: String,
+    /// The name of the generated enum for `token` attributes.
+    #[serde(rename = "enum")]
+    enum_name: Option<String>,
+    #[serde(default)]
+    values: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct AriaData {
+    attributes: Vec<AriaAttribute>,
+}
+
+fn keywords_enum(name: &str, values: &[String]) -> TokenStream {
+    let name = format_ident!("{}", name);
+    let variants = values.iter().map(|value| {
+        let variant = format_ident!("{}", value.to_upper_camel_case());
+        quote! { #variant => #value, }
+    });
+    quote! { keywords!(#name { #(#variants)* }); }
+}
+
+fn aria() {
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    let data = std::fs::read_to_string(format!("{manifest_dir}/data/aria.json")).unwrap();
+    let data: AriaData = serde_json::from_str(&data).unwrap();
+
+    let names = data.attributes.iter().map(|attribute| &attribute.name);
+
+    let tristate = ["false", "true", "mixed"].map(String::from);
+    let mut enums = vec![keywords_enum("Tristate", &tristate)];
+    enums.extend(
+        data.attributes
+            .iter()
+            .filter_map(|attribute| Some((attribute.enum_name.as_deref()?, &attribute.values)))
+            .unique_by(|(name, _)| *name)
+            .map(|(name, values)| keywords_enum(name, values)),
+    );
+
+    let setters = data.attributes.iter().map(|attribute| {
+        let name = &attribute.name;
+        let doc = &attribute.description;
+        let method_name = format_ident!("{}", name.to_snake_case());
+        let (value_type, value) = match attribute.value_type.as_str() {
+            "boolean" => (quote! { bool }, quote! { value }),
+            "tristate" => (
+                quote! { impl Into<crate::aria::Tristate> },
+                quote! { value.into() },
+            ),
+            "token" => {
+                let enum_name = format_ident!("{}", attribute.enum_name.as_ref().unwrap());
+                (quote! { crate::aria::#enum_name }, quote! { value })
+            }
+            "integer" => (quote! { i32 }, quote! { value }),
+            "unsigned" => (quote! { u32 }, quote! { value }),
+            "number" => (quote! { f64 }, quote! { value }),
+            "string" => (quote! { impl std::fmt::Display }, quote! { value }),
+            value_type => panic!("unknown ARIA value type `{value_type}` of {name}"),
+        };
+        quote! {
+            #[doc = #doc]
+            fn #method_name(self, value: #value_type) -> Self {
+                self.attribute(#name, #value)
+            }
+        }
+    });
+
+    let data = quote! {
+        use crate::macros::keywords;
+
+        /// The names of all WAI-ARIA states and properties.
+        pub const ATTRIBUTES: &[&str] = &[#(#names,)*];
+
+        #(#enums)*
+
+        /// Expands to a typed setter on the `Element` trait for every WAI-ARIA state and property.
+        macro_rules! aria_attributes {
+            () => {
+                #(#setters)*
+            };
+        }
+
+        pub(crate) use aria_attributes;
+    };
+
+    std::fs::write(
+        format!("{manifest_dir}/../src/aria_attributes.rs"),
+        data.to_string(),
+    )
+    .unwrap();
+}
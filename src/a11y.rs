@@ -0,0 +1,391 @@
+use crate::{aria, aria::Role, node::child_paths, Attributes, Node};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+/// A WCAG failure found by [`check`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub rule: Rule,
+    pub message: String,
+    /// A selector for the offending node, e.g. `body > main > img:nth-child(2)`.
+    pub path: String,
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}: {} ({:?})", self.path, self.message, self.rule)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rule {
+    /// Images need an `alt` that describes them rather than naming the file.
+    ImageAlt,
+    /// Form controls need a `label`, `aria-label` or `aria-labelledby`.
+    FormLabel,
+    /// Heading levels should only increase one at a time.
+    HeadingOrder,
+    /// Links and buttons need an accessible name.
+    EmptyInteractive,
+    /// Every `id` must be unique in the document.
+    DuplicateId,
+    /// `role` and `aria-*` attributes must be defined by WAI-ARIA.
+    InvalidAria,
+}
+
+/// Checks a tree for common accessibility failures.
+pub fn check(node: &Node) -> Vec<Diagnostic> {
+    let mut ids = HashMap::<String, usize>::new();
+    let mut labelled = Vec::new();
+    collect(node, &mut ids, &mut labelled);
+
+    let mut checker = Checker {
+        diagnostics: Vec::new(),
+        labelled,
+        reported_ids: HashSet::new(),
+        ids,
+        heading_level: None,
+    };
+    let path = node.tag_name().unwrap_or_default().to_string();
+    checker.visit(node, path, false);
+    checker.diagnostics
+}
+
+fn collect(node: &Node, ids: &mut HashMap<String, usize>, labelled: &mut Vec<String>) {
+    if let (Some(tag_name), Some(attributes)) = (node.tag_name(), node.attributes()) {
+        if let Some(id) = attributes.get("id") {
+            *ids.entry(id.to_string()).or_default() += 1;
+        }
+        if tag_name == "label" {
+            if let Some(target) = attributes.get("for") {
+                labelled.push(target.to_string());
+            }
+        }
+    }
+
+    for child in node.children() {
+        collect(child, ids, labelled);
+    }
+}
+
+struct Checker {
+    diagnostics: Vec<Diagnostic>,
+    ids: HashMap<String, usize>,
+    reported_ids: HashSet<String>,
+    labelled: Vec<String>,
+    heading_level: Option<u8>,
+}
+
+impl Checker {
+    fn report(&mut self, rule: Rule, path: &str, message: impl Display) {
+        self.diagnostics.push(Diagnostic {
+            rule,
+            message: message.to_string(),
+            path: path.to_string(),
+        });
+    }
+
+    fn visit(&mut self, node: &Node, path: String, in_label: bool) {
+        let (Some(tag_name), Some(attributes)) = (node.tag_name(), node.attributes()) else {
+            // A fragment's children take its place in the parent.
+            if let Node::Fragment(nodes) = node {
+                for (child, path) in child_paths(&path, nodes) {
+                    self.visit(child, path, in_label);
+                }
+            }
+            return;
+        };
+        let children = node.children();
+
+        self.check_image(tag_name, attributes, &path);
+        self.check_label(tag_name, attributes, &path, in_label);
+        self.check_heading(tag_name, &path);
+        self.check_interactive(tag_name, attributes, children, &path);
+        self.check_id(attributes, &path);
+        self.check_aria(attributes, &path);
+
+        let in_label = in_label || tag_name == "label";
+        for (child, path) in child_paths(&path, children) {
+            self.visit(child, path, in_label);
+        }
+    }
+
+    fn check_image(&mut self, tag_name: &str, attributes: &Attributes, path: &str) {
+        if tag_name != "img" {
+            return;
+        }
+
+        match attributes.get("alt") {
+            None => self.report(Rule::ImageAlt, path, "image is missing an `alt` attribute"),
+            Some(alt) => {
+                let file_name = attributes
+                    .get("src")
+                    .and_then(|src| src.rsplit('/').next())
+                    .unwrap_or_default();
+                if !alt.is_empty() && (alt == file_name || is_image_file_name(alt)) {
+                    self.report(
+                        Rule::ImageAlt,
+                        path,
+                        format!("image `alt` is a file name: {alt:?}"),
+                    );
+                }
+            }
+        }
+    }
+
+    fn check_label(&mut self, tag_name: &str, attributes: &Attributes, path: &str, in_label: bool) {
+        let is_control = match tag_name {
+            "select" | "textarea" => true,
+            "input" => !matches!(
+                attributes.get("type"),
+                Some("hidden" | "submit" | "reset" | "button" | "image")
+            ),
+            _ => false,
+        };
+        if !is_control || in_label || has_accessible_name(attributes) {
+            return;
+        }
+
+        let labelled = attributes
+            .get("id")
+            .is_some_and(|id| self.labelled.iter().any(|target| target == id));
+        if !labelled {
+            self.report(
+                Rule::FormLabel,
+                path,
+                format!("`{tag_name}` has no associated `label`"),
+            );
+        }
+    }
+
+    fn check_heading(&mut self, tag_name: &str, path: &str) {
+        let level = match tag_name.as_bytes() {
+            [b'h', level @ b'1'..=b'6'] => level - b'0',
+            _ => return,
+        };
+
+        if let Some(previous) = self.heading_level {
+            if level > previous + 1 {
+                self.report(
+                    Rule::HeadingOrder,
+                    path,
+                    format!("heading level skipped from h{previous} to h{level}"),
+                );
+            }
+        }
+        self.heading_level = Some(level);
+    }
+
+    fn check_interactive(
+        &mut self,
+        tag_name: &str,
+        attributes: &Attributes,
+        children: &[Node],
+        path: &str,
+    ) {
+        if !matches!(tag_name, "a" | "button") || has_accessible_name(attributes) {
+            return;
+        }
+
+        if !children.iter().any(has_text) {
+            let description = if tag_name == "a" { "link" } else { "button" };
+            self.report(
+                Rule::EmptyInteractive,
+                path,
+                format!("{description} has no text or accessible name"),
+            );
+        }
+    }
+
+    fn check_id(&mut self, attributes: &Attributes, path: &str) {
+        let Some(id) = attributes.get("id") else {
+            return;
+        };
+
+        if self.ids.get(id).is_some_and(|count| *count > 1)
+            && self.reported_ids.insert(id.to_string())
+        {
+            self.report(
+                Rule::DuplicateId,
+                path,
+                format!("id {id:?} is used {} times", self.ids[id]),
+            );
+        }
+    }
+
+    fn check_aria(&mut self, attributes: &Attributes, path: &str) {
+        if let Some(roles) = attributes.get("role") {
+            for role in roles.split_ascii_whitespace() {
+                if role.parse::<Role>().is_err() {
+                    self.report(Rule::InvalidAria, path, format!("invalid role {role:?}"));
+                }
+            }
+        }
+
+        let mut invalid = attributes
+            .0
+            .keys()
+            .filter(|key| key.starts_with("aria-") && !aria::ATTRIBUTES.contains(&key.as_str()))
+            .collect::<Vec<_>>();
+        invalid.sort();
+        for key in invalid {
+            self.report(
+                Rule::InvalidAria,
+                path,
+                format!("invalid ARIA attribute `{key}`"),
+            );
+        }
+    }
+}
+
+fn has_accessible_name(attributes: &Attributes) -> bool {
+    ["aria-label", "aria-labelledby", "title"]
+        .iter()
+        .any(|key| {
+            attributes
+                .get(key)
+                .is_some_and(|value| !value.trim().is_empty())
+        })
+}
+
+fn has_text(node: &Node) -> bool {
+    match node {
+        Node::Text(text) | Node::RawText(text) => !text.trim().is_empty(),
+        Node::VoidElement(element) => {
+            element.tag_name == "img"
+                && element
+                    .attributes
+                    .get("alt")
+                    .is_some_and(|alt| !alt.trim().is_empty())
+        }
+        Node::Element(element) => {
+            has_accessible_name(&element.attributes) || element.children.iter().any(has_text)
+        }
+        Node::Fragment(nodes) => nodes.iter().any(has_text),
+        Node::Comment(_) | Node::Doctype(_) => false,
+    }
+}
+
+fn is_image_file_name(alt: &str) -> bool {
+    let alt = alt.to_ascii_lowercase();
+    [".png", ".jpg", ".jpeg", ".gif", ".svg", ".webp", ".avif"]
+        .iter()
+        .any(|extension| alt.ends_with(extension))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{
+        a, button, div, h1, h2, h3, h4, img, input, label, text_input, textarea, Children, Element,
+        InputType,
+    };
+
+    fn diagnostics(node: impl Into<Node>) -> Vec<(Rule, String)> {
+        check(&node.into())
+            .into_iter()
+            .map(|diagnostic| (diagnostic.rule, diagnostic.path))
+            .collect()
+    }
+
+    #[test]
+    fn checks_fragment_children() {
+        let fragment = Node::Fragment(vec![
+            div().id("a").into(),
+            Node::Fragment(vec![div().id("a").child(img("cat.png", "cat.png")).into()]),
+        ]);
+        assert_eq!(
+            diagnostics(fragment),
+            [
+                (Rule::DuplicateId, "div:nth-child(1)".to_string()),
+                (Rule::ImageAlt, "div:nth-child(2) > img".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn form_labels() {
+        let form = div()
+            .child(text_input("a"))
+            .child(label("b").child("B"))
+            .child(text_input("b").id("b"))
+            .child(label("").child("C").child(text_input("c")))
+            .child(text_input("d").attribute("aria-label", "D"))
+            .child(input(InputType::Hidden, "e"))
+            .child(input(InputType::Submit, "f"))
+            .child(textarea("g", "g"));
+        assert_eq!(
+            diagnostics(form),
+            [
+                (Rule::FormLabel, "div > input:nth-child(1)".to_string()),
+                (Rule::FormLabel, "div > textarea:nth-child(8)".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn heading_order() {
+        let page = div()
+            .child(h1("a"))
+            .child(h2("b"))
+            .child(h4("c"))
+            .child(h2("d"))
+            .child(h3("e"));
+        assert_eq!(
+            diagnostics(page),
+            [(Rule::HeadingOrder, "div > h4:nth-child(3)".to_string())]
+        );
+    }
+
+    #[test]
+    fn empty_interactive() {
+        let page = div()
+            .child(a("a"))
+            .child(a("b").child("B"))
+            .child(a("c").child(img("c.png", "Home")))
+            .child(button("d").child(" "))
+            .child(button("e").attribute("aria-label", "Close"))
+            .child(a("f").child(div().child("F")));
+        assert_eq!(
+            diagnostics(page),
+            [
+                (Rule::EmptyInteractive, "div > a:nth-child(1)".to_string()),
+                (
+                    Rule::EmptyInteractive,
+                    "div > button:nth-child(4)".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_aria() {
+        let page = div().child(div().attribute("role", "button foo")).child(
+            div()
+                .attribute("aria-label", "a")
+                .attribute("aria-lable", "b"),
+        );
+        assert_eq!(
+            diagnostics(page),
+            [
+                (Rule::InvalidAria, "div > div:nth-child(1)".to_string()),
+                (Rule::InvalidAria, "div > div:nth-child(2)".to_string()),
+            ]
+        );
+        let messages = check(
+            &div()
+                .attribute("role", "foo")
+                .attribute("aria-x", "")
+                .into(),
+        )
+        .into_iter()
+        .map(|diagnostic| diagnostic.message)
+        .collect::<Vec<_>>();
+        assert_eq!(
+            messages,
+            ["invalid role \"foo\"", "invalid ARIA attribute `aria-x`"]
+        );
+    }
+}
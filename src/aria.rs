@@ -0,0 +1,135 @@
+use crate::macros::keywords;
+
+pub use crate::aria_attributes::{
+    AutoComplete, Current, HasPopup, Invalid, Orientation, Politeness, Sort, Tristate, ATTRIBUTES,
+};
+
+keywords!(
+    /// A WAI-ARIA role.
+    Role {
+        Alert => "alert",
+        AlertDialog => "alertdialog",
+        Application => "application",
+        Article => "article",
+        Banner => "banner",
+        Blockquote => "blockquote",
+        Button => "button",
+        Caption => "caption",
+        Cell => "cell",
+        Checkbox => "checkbox",
+        Code => "code",
+        ColumnHeader => "columnheader",
+        Combobox => "combobox",
+        Complementary => "complementary",
+        ContentInfo => "contentinfo",
+        Definition => "definition",
+        Deletion => "deletion",
+        Dialog => "dialog",
+        Document => "document",
+        Emphasis => "emphasis",
+        Feed => "feed",
+        Figure => "figure",
+        Form => "form",
+        Generic => "generic",
+        Grid => "grid",
+        GridCell => "gridcell",
+        Group => "group",
+        Heading => "heading",
+        Img => "img",
+        Insertion => "insertion",
+        Link => "link",
+        List => "list",
+        Listbox => "listbox",
+        ListItem => "listitem",
+        Log => "log",
+        Main => "main",
+        Marquee => "marquee",
+        Math => "math",
+        Menu => "menu",
+        Menubar => "menubar",
+        MenuItem => "menuitem",
+        MenuItemCheckbox => "menuitemcheckbox",
+        MenuItemRadio => "menuitemradio",
+        Meter => "meter",
+        Navigation => "navigation",
+        None => "none",
+        Note => "note",
+        Option => "option",
+        Paragraph => "paragraph",
+        Presentation => "presentation",
+        ProgressBar => "progressbar",
+        Radio => "radio",
+        RadioGroup => "radiogroup",
+        Region => "region",
+        Row => "row",
+        RowGroup => "rowgroup",
+        RowHeader => "rowheader",
+        Scrollbar => "scrollbar",
+        Search => "search",
+        Searchbox => "searchbox",
+        Separator => "separator",
+        Slider => "slider",
+        SpinButton => "spinbutton",
+        Status => "status",
+        Strong => "strong",
+        Subscript => "subscript",
+        Superscript => "superscript",
+        Switch => "switch",
+        Tab => "tab",
+        Table => "table",
+        TabList => "tablist",
+        TabPanel => "tabpanel",
+        Term => "term",
+        Textbox => "textbox",
+        Time => "time",
+        Timer => "timer",
+        Toolbar => "toolbar",
+        Tooltip => "tooltip",
+        Tree => "tree",
+        TreeGrid => "treegrid",
+        TreeItem => "treeitem",
+    }
+);
+
+impl From<bool> for Tristate {
+    fn from(value: bool) -> Self {
+        if value {
+            Self::True
+        } else {
+            Self::False
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn tristate_setters_accept_bool() {
+        let checkbox = div().aria_checked(true).aria_pressed(Tristate::Mixed);
+        assert_eq!(checkbox.attributes().get("aria-checked"), Some("true"));
+        assert_eq!(checkbox.attributes().get("aria-pressed"), Some("mixed"));
+        assert_eq!(
+            div().aria_checked(false).attributes().get("aria-checked"),
+            Some("false")
+        );
+    }
+
+    #[test]
+    fn generated_setters() {
+        let element = div()
+            .aria_live(Politeness::Polite)
+            .aria_level(2)
+            .aria_hidden(true)
+            .aria_label("Close");
+        let attributes = element.attributes();
+        assert_eq!(attributes.get("aria-live"), Some("polite"));
+        assert_eq!(attributes.get("aria-level"), Some("2"));
+        assert_eq!(attributes.get("aria-hidden"), Some("true"));
+        assert_eq!(attributes.get("aria-label"), Some("Close"));
+        assert!(ATTRIBUTES.contains(&"aria-valuetext"));
+        assert_eq!("listbox".parse(), Ok(HasPopup::Listbox));
+    }
+}
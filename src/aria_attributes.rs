@@ -0,0 +1,66 @@
+use crate::macros::keywords;
+#[doc = r" The names of all WAI-ARIA states and properties."]
+pub const ATTRIBUTES: &[&str] = &[
+    "aria-activedescendant",
+    "aria-atomic",
+    "aria-autocomplete",
+    "aria-braillelabel",
+    "aria-brailleroledescription",
+    "aria-busy",
+    "aria-checked",
+    "aria-colcount",
+    "aria-colindex",
+    "aria-colindextext",
+    "aria-colspan",
+    "aria-controls",
+    "aria-current",
+    "aria-describedby",
+    "aria-description",
+    "aria-details",
+    "aria-disabled",
+    "aria-errormessage",
+    "aria-expanded",
+    "aria-flowto",
+    "aria-haspopup",
+    "aria-hidden",
+    "aria-invalid",
+    "aria-keyshortcuts",
+    "aria-label",
+    "aria-labelledby",
+    "aria-level",
+    "aria-live",
+    "aria-modal",
+    "aria-multiline",
+    "aria-multiselectable",
+    "aria-orientation",
+    "aria-owns",
+    "aria-placeholder",
+    "aria-posinset",
+    "aria-pressed",
+    "aria-readonly",
+    "aria-relevant",
+    "aria-required",
+    "aria-roledescription",
+    "aria-rowcount",
+    "aria-rowindex",
+    "aria-rowindextext",
+    "aria-rowspan",
+    "aria-selected",
+    "aria-setsize",
+    "aria-sort",
+    "aria-valuemax",
+    "aria-valuemin",
+    "aria-valuenow",
+    "aria-valuetext",
+];
+keywords ! (Tristate { False => "false" , True => "true" , Mixed => "mixed" , });
+keywords ! (AutoComplete { None => "none" , Inline => "inline" , List => "list" , Both => "both" , });
+keywords ! (Current { False => "false" , True => "true" , Page => "page" , Step => "step" , Location => "location" , Date => "date" , Time => "time" , });
+keywords ! (HasPopup { False => "false" , True => "true" , Menu => "menu" , Listbox => "listbox" , Tree => "tree" , Grid => "grid" , Dialog => "dialog" , });
+keywords ! (Invalid { False => "false" , True => "true" , Grammar => "grammar" , Spelling => "spelling" , });
+keywords ! (Politeness { Off => "off" , Polite => "polite" , Assertive => "assertive" , });
+keywords ! (Orientation { Horizontal => "horizontal" , Vertical => "vertical" , Undefined => "undefined" , });
+keywords ! (Sort { None => "none" , Ascending => "ascending" , Descending => "descending" , Other => "other" , });
+#[doc = r" Expands to a typed setter on the `Element` trait for every WAI-ARIA state and property."]
+macro_rules ! aria_attributes { () => { # [doc = "Identifies the currently active element when focus is on a composite widget."] fn aria_activedescendant (self , value : impl std :: fmt :: Display) -> Self { self . attribute ("aria-activedescendant" , value) } # [doc = "Whether assistive technologies present all, or only parts of, the changed region."] fn aria_atomic (self , value : bool) -> Self { self . attribute ("aria-atomic" , value) } # [doc = "Whether input text could trigger display of predictions of the user's intended value."] fn aria_autocomplete (self , value : crate :: aria :: AutoComplete) -> Self { self . attribute ("aria-autocomplete" , value) } # [doc = "A braille-specific label for the element."] fn aria_braillelabel (self , value : impl std :: fmt :: Display) -> Self { self . attribute ("aria-braillelabel" , value) } # [doc = "A braille-specific role description for the element."] fn aria_brailleroledescription (self , value : impl std :: fmt :: Display) -> Self { self . attribute ("aria-brailleroledescription" , value) } # [doc = "Whether the element is being modified."] fn aria_busy (self , value : bool) -> Self { self . attribute ("aria-busy" , value) } # [doc = "The checked state of checkboxes, radio buttons, and other widgets."] fn aria_checked (self , value : impl Into < crate :: aria :: Tristate >) -> Self { self . attribute ("aria-checked" , value . into ()) } # [doc = "The total number of columns in a table, grid, or treegrid."] fn aria_colcount (self , value : i32) -> Self { self . attribute ("aria-colcount" , value) } # [doc = "The column index of the element within a table, grid, or treegrid."] fn aria_colindex (self , value : u32) -> Self { self . attribute ("aria-colindex" , value) } # [doc = "Human readable text alternative of `aria-colindex`."] fn aria_colindextext (self , value : impl std :: fmt :: Display) -> Self { self . attribute ("aria-colindextext" , value) } # [doc = "The number of columns spanned by a cell."] fn aria_colspan (self , value : u32) -> Self { self . attribute ("aria-colspan" , value) } # [doc = "The ids of the elements whose contents or presence are controlled by this element."] fn aria_controls (self , value : impl std :: fmt :: Display) -> Self { self . attribute ("aria-controls" , value) } # [doc = "The element that represents the current item within a set of related elements."] fn aria_current (self , value : crate :: aria :: Current) -> Self { self . attribute ("aria-current" , value) } # [doc = "The ids of the elements that describe this element."] fn aria_describedby (self , value : impl std :: fmt :: Display) -> Self { self . attribute ("aria-describedby" , value) } # [doc = "A string value that describes the element."] fn aria_description (self , value : impl std :: fmt :: Display) -> Self { self . attribute ("aria-description" , value) } # [doc = "The ids of the elements that provide an extended description."] fn aria_details (self , value : impl std :: fmt :: Display) -> Self { self . attribute ("aria-details" , value) } # [doc = "Whether the element is perceivable but disabled."] fn aria_disabled (self , value : bool) -> Self { self . attribute ("aria-disabled" , value) } # [doc = "The id of the element that provides an error message for this element."] fn aria_errormessage (self , value : impl std :: fmt :: Display) -> Self { self . attribute ("aria-errormessage" , value) } # [doc = "Whether the grouping element this element owns or controls is expanded."] fn aria_expanded (self , value : bool) -> Self { self . attribute ("aria-expanded" , value) } # [doc = "The ids of the next elements in an alternate reading order."] fn aria_flowto (self , value : impl std :: fmt :: Display) -> Self { self . attribute ("aria-flowto" , value) } # [doc = "The type of interactive popup element that can be triggered by this element."] fn aria_haspopup (self , value : crate :: aria :: HasPopup) -> Self { self . attribute ("aria-haspopup" , value) } # [doc = "Whether the element is exposed to an accessibility API."] fn aria_hidden (self , value : bool) -> Self { self . attribute ("aria-hidden" , value) } # [doc = "Whether the entered value does not conform to the expected format."] fn aria_invalid (self , value : crate :: aria :: Invalid) -> Self { self . attribute ("aria-invalid" , value) } # [doc = "Keyboard shortcuts implemented to activate or focus the element."] fn aria_keyshortcuts (self , value : impl std :: fmt :: Display) -> Self { self . attribute ("aria-keyshortcuts" , value) } # [doc = "A string value that labels the element."] fn aria_label (self , value : impl std :: fmt :: Display) -> Self { self . attribute ("aria-label" , value) } # [doc = "The ids of the elements that label this element."] fn aria_labelledby (self , value : impl std :: fmt :: Display) -> Self { self . attribute ("aria-labelledby" , value) } # [doc = "The hierarchical level of the element within a structure."] fn aria_level (self , value : u32) -> Self { self . attribute ("aria-level" , value) } # [doc = "Whether the element will be updated, and the type of updates to expect."] fn aria_live (self , value : crate :: aria :: Politeness) -> Self { self . attribute ("aria-live" , value) } # [doc = "Whether the element is modal when displayed."] fn aria_modal (self , value : bool) -> Self { self . attribute ("aria-modal" , value) } # [doc = "Whether a text box accepts multiple lines of input."] fn aria_multiline (self , value : bool) -> Self { self . attribute ("aria-multiline" , value) } # [doc = "Whether more than one item can be selected from the descendants."] fn aria_multiselectable (self , value : bool) -> Self { self . attribute ("aria-multiselectable" , value) } # [doc = "Whether the element's orientation is horizontal, vertical, or undefined."] fn aria_orientation (self , value : crate :: aria :: Orientation) -> Self { self . attribute ("aria-orientation" , value) } # [doc = "The ids of elements that are children of this element but not DOM descendants."] fn aria_owns (self , value : impl std :: fmt :: Display) -> Self { self . attribute ("aria-owns" , value) } # [doc = "A hint to aid the user with data entry when the control has no value."] fn aria_placeholder (self , value : impl std :: fmt :: Display) -> Self { self . attribute ("aria-placeholder" , value) } # [doc = "The element's position in the current set of listitems or treeitems."] fn aria_posinset (self , value : u32) -> Self { self . attribute ("aria-posinset" , value) } # [doc = "The pressed state of toggle buttons."] fn aria_pressed (self , value : impl Into < crate :: aria :: Tristate >) -> Self { self . attribute ("aria-pressed" , value . into ()) } # [doc = "Whether the element is not editable, but otherwise operable."] fn aria_readonly (self , value : bool) -> Self { self . attribute ("aria-readonly" , value) } # [doc = "What notifications will be triggered when the accessibility tree within a live region is modified."] fn aria_relevant (self , value : impl std :: fmt :: Display) -> Self { self . attribute ("aria-relevant" , value) } # [doc = "Whether user input is required before a form may be submitted."] fn aria_required (self , value : bool) -> Self { self . attribute ("aria-required" , value) } # [doc = "A human-readable, author-localized description for the role of the element."] fn aria_roledescription (self , value : impl std :: fmt :: Display) -> Self { self . attribute ("aria-roledescription" , value) } # [doc = "The total number of rows in a table, grid, or treegrid."] fn aria_rowcount (self , value : i32) -> Self { self . attribute ("aria-rowcount" , value) } # [doc = "The row index of the element within a table, grid, or treegrid."] fn aria_rowindex (self , value : u32) -> Self { self . attribute ("aria-rowindex" , value) } # [doc = "Human readable text alternative of `aria-rowindex`."] fn aria_rowindextext (self , value : impl std :: fmt :: Display) -> Self { self . attribute ("aria-rowindextext" , value) } # [doc = "The number of rows spanned by a cell."] fn aria_rowspan (self , value : u32) -> Self { self . attribute ("aria-rowspan" , value) } # [doc = "The selected state of the element."] fn aria_selected (self , value : bool) -> Self { self . attribute ("aria-selected" , value) } # [doc = "The number of items in the current set of listitems or treeitems."] fn aria_setsize (self , value : i32) -> Self { self . attribute ("aria-setsize" , value) } # [doc = "Whether items in a table or grid are sorted in ascending or descending order."] fn aria_sort (self , value : crate :: aria :: Sort) -> Self { self . attribute ("aria-sort" , value) } # [doc = "The maximum allowed value for a range widget."] fn aria_valuemax (self , value : f64) -> Self { self . attribute ("aria-valuemax" , value) } # [doc = "The minimum allowed value for a range widget."] fn aria_valuemin (self , value : f64) -> Self { self . attribute ("aria-valuemin" , value) } # [doc = "The current value for a range widget."] fn aria_valuenow (self , value : f64) -> Self { self . attribute ("aria-valuenow" , value) } # [doc = "The human readable text alternative of `aria-valuenow`."] fn aria_valuetext (self , value : impl std :: fmt :: Display) -> Self { self . attribute ("aria-valuetext" , value) } } ; }
+pub(crate) use aria_attributes;
@@ -0,0 +1,321 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    io,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::OnceLock,
+};
+
+/// The name of the manifest file written by [`build`].
+pub const MANIFEST_FILE: &str = "asset-manifest.txt";
+
+static MANIFEST: OnceLock<Manifest> = OnceLock::new();
+
+/// A map between the original names of static assets and their content-hashed names.
+#[derive(Debug, Clone)]
+pub struct Manifest {
+    prefix: String,
+    fingerprinted: HashMap<String, String>,
+    originals: HashMap<String, String>,
+    files: HashMap<String, PathBuf>,
+}
+
+impl Default for Manifest {
+    fn default() -> Self {
+        Self {
+            prefix: "/static".to_string(),
+            fingerprinted: HashMap::new(),
+            originals: HashMap::new(),
+            files: HashMap::new(),
+        }
+    }
+}
+
+impl Manifest {
+    /// Hashes every file below `dir`, serving them from their original location.
+    pub fn scan(dir: impl AsRef<Path>) -> io::Result<Self> {
+        let dir = dir.as_ref();
+        let mut manifest = Self::default();
+
+        for original in walk(dir)? {
+            let path = dir.join(&original);
+            let fingerprinted = fingerprint(&original, &std::fs::read(&path)?);
+            manifest.insert(original, fingerprinted, path);
+        }
+
+        Ok(manifest)
+    }
+
+    /// Reads a manifest written by [`build`], serving files from the directory containing it.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+        let dir = path.parent().unwrap_or(Path::new(""));
+        let mut manifest = std::fs::read_to_string(path)?
+            .parse::<Self>()
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+
+        for (fingerprinted, file) in &mut manifest.files {
+            *file = dir.join(fingerprinted);
+        }
+
+        Ok(manifest)
+    }
+
+    /// Sets the URL path the assets are served under, `/static` by default.
+    pub fn with_prefix(mut self, prefix: impl Display) -> Self {
+        self.prefix = prefix.to_string().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Makes this manifest the one used by [`asset`].
+    ///
+    /// Returns the manifest back if one has already been installed.
+    pub fn install(self) -> Result<(), Box<Self>> {
+        MANIFEST.set(self).map_err(Box::new)
+    }
+
+    fn insert(&mut self, original: String, fingerprinted: String, file: PathBuf) {
+        self.files.insert(fingerprinted.clone(), file);
+        self.originals
+            .insert(fingerprinted.clone(), original.clone());
+        self.fingerprinted.insert(original, fingerprinted);
+    }
+
+    /// The fingerprinted name of an original asset name.
+    pub fn get(&self, original: &str) -> Option<&str> {
+        self.fingerprinted.get(original).map(String::as_str)
+    }
+
+    /// The original name of a fingerprinted asset name.
+    pub fn original(&self, fingerprinted: &str) -> Option<&str> {
+        self.originals.get(fingerprinted).map(String::as_str)
+    }
+
+    /// The `fingerprinted name -> original` map.
+    pub fn originals(&self) -> &HashMap<String, String> {
+        &self.originals
+    }
+
+    /// The URL an asset is served from, falling back to the original name for unknown assets.
+    pub fn url(&self, original: &str) -> String {
+        let original = original.trim_start_matches('/');
+        format!("{}/{}", self.prefix, self.get(original).unwrap_or(original))
+    }
+
+    /// Serves a fingerprinted asset with long-lived cache headers.
+    ///
+    /// Returns `None` if the path is not a fingerprinted asset under the prefix.
+    pub fn response(
+        &self,
+        path: &str,
+    ) -> Option<hyper::Response<http_body_util::Full<hyper::body::Bytes>>> {
+        let fingerprinted = path.strip_prefix(&self.prefix)?.trim_start_matches('/');
+        let original = self.original(fingerprinted)?;
+        let body = std::fs::read(self.files.get(fingerprinted)?).ok()?;
+
+        Some(
+            hyper::Response::builder()
+                .header(hyper::header::CONTENT_TYPE, content_type(original))
+                .header(
+                    hyper::header::CACHE_CONTROL,
+                    "public, max-age=31536000, immutable",
+                )
+                .body(http_body_util::Full::new(hyper::body::Bytes::from(body)))
+                .unwrap(),
+        )
+    }
+}
+
+impl Display for Manifest {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let mut entries = self.originals.iter().collect::<Vec<_>>();
+        entries.sort();
+        for (fingerprinted, original) in entries {
+            writeln!(f, "{fingerprinted}\t{original}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Manifest {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut manifest = Self::default();
+
+        for line in s.lines().filter(|line| !line.is_empty()) {
+            let (fingerprinted, original) = line
+                .split_once('\t')
+                .ok_or_else(|| format!("invalid manifest line: {line}"))?;
+            manifest.insert(
+                original.to_string(),
+                fingerprinted.to_string(),
+                PathBuf::from(fingerprinted),
+            );
+        }
+
+        Ok(manifest)
+    }
+}
+
+/// Build script entry point: copies every file below `source` into `out` under its
+/// fingerprinted name and writes a [`MANIFEST_FILE`] next to them.
+pub fn build(source: impl AsRef<Path>, out: impl AsRef<Path>) -> io::Result<Manifest> {
+    let (source, out) = (source.as_ref(), out.as_ref());
+    println!("cargo:rerun-if-changed={}", source.display());
+
+    let manifest = Manifest::scan(source)?;
+    for (fingerprinted, original) in manifest.originals() {
+        let destination = out.join(fingerprinted);
+        if let Some(parent) = destination.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::copy(source.join(original), destination)?;
+    }
+    std::fs::write(out.join(MANIFEST_FILE), manifest.to_string())?;
+
+    Manifest::load(out.join(MANIFEST_FILE))
+}
+
+/// The URL of an asset according to the installed [`Manifest`].
+pub fn asset(original: &str) -> String {
+    match MANIFEST.get() {
+        Some(manifest) => manifest.url(original),
+        None => Manifest::default().url(original),
+    }
+}
+
+fn walk(dir: &Path) -> io::Result<Vec<String>> {
+    let mut files = Vec::new();
+    let mut pending = vec![PathBuf::new()];
+
+    while let Some(relative) = pending.pop() {
+        for entry in std::fs::read_dir(dir.join(&relative))? {
+            let entry = entry?;
+            let path = relative.join(entry.file_name());
+            if entry.file_type()?.is_dir() {
+                pending.push(path);
+            } else {
+                files.push(
+                    path.components()
+                        .map(|component| component.as_os_str().to_string_lossy())
+                        .collect::<Vec<_>>()
+                        .join("/"),
+                );
+            }
+        }
+    }
+
+    Ok(files)
+}
+
+/// FNV-1a, which is stable across builds unlike `DefaultHasher`.
+pub(crate) fn hash(contents: &[u8]) -> u64 {
+    contents.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+fn fingerprint(original: &str, contents: &[u8]) -> String {
+    let hash = format!("{:016x}", hash(contents));
+    let (dir, file) = original.rsplit_once('/').unwrap_or(("", original));
+    let file = match file.rsplit_once('.') {
+        Some((stem, extension)) if !stem.is_empty() => format!("{stem}.{hash}.{extension}"),
+        _ => format!("{file}.{hash}"),
+    };
+
+    if dir.is_empty() {
+        file
+    } else {
+        format!("{dir}/{file}")
+    }
+}
+
+fn content_type(original: &str) -> &'static str {
+    match original.rsplit_once('.').map(|(_, extension)| extension) {
+        Some("css") => "text/css; charset=utf-8",
+        Some("js" | "mjs") => "text/javascript; charset=utf-8",
+        Some("json" | "map") => "application/json",
+        Some("html") => "text/html; charset=utf-8",
+        Some("txt") => "text/plain; charset=utf-8",
+        Some("svg") => "image/svg+xml",
+        Some("png") => "image/png",
+        Some("jpg" | "jpeg") => "image/jpeg",
+        Some("gif") => "image/gif",
+        Some("webp") => "image/webp",
+        Some("avif") => "image/avif",
+        Some("ico") => "image/x-icon",
+        Some("woff") => "font/woff",
+        Some("woff2") => "font/woff2",
+        Some("wasm") => "application/wasm",
+        _ => "application/octet-stream",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("html-builder-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("css")).unwrap();
+        dir
+    }
+
+    #[test]
+    fn fingerprint_inserts_hash_before_extension() {
+        let hash = format!("{:016x}", hash(b"body {}"));
+        assert_eq!(
+            fingerprint("css/app.css", b"body {}"),
+            format!("css/app.{hash}.css")
+        );
+        assert_eq!(
+            fingerprint("LICENSE", b"body {}"),
+            format!("LICENSE.{hash}")
+        );
+        assert_eq!(fingerprint(".env", b"body {}"), format!(".env.{hash}"));
+        assert_ne!(fingerprint("app.css", b"a"), fingerprint("app.css", b"b"));
+    }
+
+    #[test]
+    fn manifest_round_trips_through_display() {
+        let manifest = "app.0123.js\tapp.js\ncss/app.4567.css\tcss/app.css\n"
+            .parse::<Manifest>()
+            .unwrap();
+        assert_eq!(manifest.get("css/app.css"), Some("css/app.4567.css"));
+        assert_eq!(manifest.original("app.0123.js"), Some("app.js"));
+
+        let reparsed = manifest.to_string().parse::<Manifest>().unwrap();
+        assert_eq!(reparsed.originals(), manifest.originals());
+        assert_eq!(reparsed.to_string(), manifest.to_string());
+        assert!("no tab".parse::<Manifest>().is_err());
+    }
+
+    #[test]
+    fn response_strips_prefix() {
+        let dir = temp_dir("response");
+        std::fs::write(dir.join("css/app.css"), "body {}").unwrap();
+        let manifest = Manifest::scan(&dir).unwrap().with_prefix("/assets/");
+        let fingerprinted = manifest.get("css/app.css").unwrap().to_string();
+
+        let url = manifest.url("/css/app.css");
+        assert_eq!(url, format!("/assets/{fingerprinted}"));
+        let response = manifest.response(&url).unwrap();
+        assert_eq!(
+            response.headers()[hyper::header::CONTENT_TYPE],
+            "text/css; charset=utf-8"
+        );
+        assert!(response.headers()[hyper::header::CACHE_CONTROL]
+            .to_str()
+            .unwrap()
+            .contains("immutable"));
+
+        assert!(manifest
+            .response(&format!("/static/{fingerprinted}"))
+            .is_none());
+        assert!(manifest.response("/assets/css/app.css").is_none());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -1,3 +1,4 @@
+use crate::macros::keywords;
 use html_escape::encode_safe as escape;
 use itertools::Itertools;
 use std::{collections::HashMap, fmt::Display};
@@ -5,6 +6,77 @@ use std::{collections::HashMap, fmt::Display};
 #[derive(Debug, Clone, Default)]
 pub struct Attributes(pub HashMap<String, String>);
 
+impl Attributes {
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).map(String::as_str)
+    }
+
+    /// Sets an attribute, adding to the class list for `class` and merging declarations for
+    /// `style` instead of replacing the previous value.
+    pub fn set(&mut self, key: String, value: String) {
+        match key.as_str() {
+            "class" => self.add_class(&value),
+            "style" => {
+                let merged = crate::style::merge(self.get("style").unwrap_or_default(), &value);
+                self.0.insert(key, merged);
+            }
+            _ => {
+                self.0.insert(key, value);
+            }
+        }
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<String> {
+        self.0.remove(key)
+    }
+
+    /// The class list, split on ASCII whitespace like the DOM `classList`.
+    pub fn classes(&self) -> impl Iterator<Item = &str> {
+        self.get("class")
+            .unwrap_or_default()
+            .split_ascii_whitespace()
+    }
+
+    pub fn has_class(&self, class: &str) -> bool {
+        self.classes().any(|existing| existing == class)
+    }
+
+    /// Adds one or more whitespace-separated classes, ignoring those already present.
+    pub fn add_class(&mut self, class: &str) {
+        for class in class.split_ascii_whitespace() {
+            if self.has_class(class) {
+                continue;
+            }
+
+            self.0
+                .entry("class".to_string())
+                .and_modify(|classes| {
+                    if !classes.trim().is_empty() {
+                        classes.push(' ');
+                    }
+                    classes.push_str(class)
+                })
+                .or_insert_with(|| class.to_string());
+        }
+    }
+
+    /// Removes one or more whitespace-separated classes, removing the attribute entirely once no
+    /// classes are left.
+    pub fn remove_class(&mut self, class: &str) {
+        let removed = class.split_ascii_whitespace().collect::<Vec<_>>();
+        let classes = self
+            .classes()
+            .filter(|existing| !removed.contains(existing))
+            .join(" ");
+
+        if classes.is_empty() {
+            self.0.remove("class");
+        } else {
+            self.0.insert("class".to_string(), classes);
+        }
+    }
+}
+
 impl Display for Attributes {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         if self.0.is_empty() {
@@ -22,3 +94,233 @@ impl Display for Attributes {
         )
     }
 }
+
+/// Whether `key` is a valid name for a `data-*` attribute, excluding the `data-` prefix.
+pub fn is_valid_data_key(key: &str) -> bool {
+    !key.is_empty()
+        && !key.to_ascii_lowercase().starts_with("xml")
+        && key.chars().all(|c| {
+            c.is_ascii_lowercase()
+                || c.is_ascii_digit()
+                || matches!(c, '-' | '_' | '.')
+                || (!c.is_ascii() && !c.is_whitespace())
+        })
+}
+
+/// Whether `name` is a valid custom element name: starting with a lowercase ASCII letter,
+/// containing a hyphen, without uppercase ASCII letters, and not one of the names reserved by
+/// SVG and MathML.
+pub const fn is_valid_custom_element_name(name: &str) -> bool {
+    const RESERVED: &[&str] = &[
+        "annotation-xml",
+        "color-profile",
+        "font-face",
+        "font-face-src",
+        "font-face-uri",
+        "font-face-format",
+        "font-face-name",
+        "missing-glyph",
+    ];
+
+    let bytes = name.as_bytes();
+    if bytes.is_empty() || !bytes[0].is_ascii_lowercase() {
+        return false;
+    }
+    let mut hyphen = false;
+    let mut index = 0;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'-' => hyphen = true,
+            b'.' | b'_' | b'0'..=b'9' | b'a'..=b'z' | 0x80.. => {}
+            _ => return false,
+        }
+        index += 1;
+    }
+
+    let mut index = 0;
+    while index < RESERVED.len() {
+        if eq_bytes(bytes, RESERVED[index].as_bytes()) {
+            return false;
+        }
+        index += 1;
+    }
+    hyphen
+}
+
+const fn eq_bytes(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut index = 0;
+    while index < a.len() {
+        if a[index] != b[index] {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
+
+/// An error from [`Element::data`](crate::Element::data) or
+/// [`Element::data_json`](crate::Element::data_json).
+#[derive(Debug)]
+pub enum DataError {
+    /// The key isn't a valid custom data attribute name.
+    InvalidKey(String),
+    Json(serde_json::Error),
+}
+
+impl Display for DataError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::InvalidKey(key) => write!(f, "invalid data attribute name: {key:?}"),
+            Self::Json(error) => write!(f, "failed to serialize data attribute: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for DataError {}
+
+/// A value accepted by [`classes!`](crate::classes), which skips `None` values.
+pub trait ClassName {
+    fn push_class(&self, classes: &mut Vec<String>);
+}
+
+impl ClassName for str {
+    fn push_class(&self, classes: &mut Vec<String>) {
+        for class in self.split_ascii_whitespace() {
+            if !classes.iter().any(|existing| existing == class) {
+                classes.push(class.to_string());
+            }
+        }
+    }
+}
+
+impl ClassName for String {
+    fn push_class(&self, classes: &mut Vec<String>) {
+        self.as_str().push_class(classes)
+    }
+}
+
+impl<T: ClassName + ?Sized> ClassName for &T {
+    fn push_class(&self, classes: &mut Vec<String>) {
+        (**self).push_class(classes)
+    }
+}
+
+impl<T: ClassName> ClassName for Option<T> {
+    fn push_class(&self, classes: &mut Vec<String>) {
+        if let Some(class) = self {
+            class.push_class(classes)
+        }
+    }
+}
+
+keywords!(Dir {
+    Ltr => "ltr",
+    Rtl => "rtl",
+    Auto => "auto",
+});
+
+keywords!(Popover {
+    Auto => "auto",
+    Manual => "manual",
+    Hint => "hint",
+});
+
+keywords!(EnterKeyHint {
+    Enter => "enter",
+    Done => "done",
+    Go => "go",
+    Next => "next",
+    Previous => "previous",
+    Search => "search",
+    Send => "send",
+});
+
+keywords!(ContentEditable {
+    True => "true",
+    False => "false",
+    PlaintextOnly => "plaintext-only",
+});
+
+keywords!(Autocapitalize {
+    Off => "off",
+    On => "on",
+    Words => "words",
+    Characters => "characters",
+});
+
+keywords!(InputMode {
+    None => "none",
+    Text => "text",
+    Decimal => "decimal",
+    Numeric => "numeric",
+    Tel => "tel",
+    Search => "search",
+    Email => "email",
+    Url => "url",
+});
+
+keywords!(ShadowRootMode {
+    Open => "open",
+    Closed => "closed",
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{div, Element};
+
+    #[test]
+    fn add_class_splits_whitespace() {
+        let mut attributes = Attributes::default();
+        attributes.add_class("a  b\tc");
+        attributes.add_class("b d");
+        assert_eq!(attributes.get("class"), Some("a b c d"));
+        assert!(attributes.has_class("a"));
+        assert!(!attributes.has_class("a b"));
+
+        attributes.remove_class("a");
+        assert_eq!(attributes.get("class"), Some("b c d"));
+        attributes.remove_class("b c\nd");
+        assert_eq!(attributes.get("class"), None);
+    }
+
+    #[test]
+    fn set_class_merges() {
+        let mut attributes = Attributes::default();
+        attributes.set("class".to_string(), "a b".to_string());
+        attributes.set("class".to_string(), "b c".to_string());
+        assert_eq!(attributes.classes().collect::<Vec<_>>(), ["a", "b", "c"]);
+        attributes.add_class("");
+        assert_eq!(attributes.get("class"), Some("a b c"));
+    }
+
+    #[test]
+    fn data_attributes() {
+        let element = div().data("user-id", 1).unwrap();
+        assert_eq!(element.attributes().get("data-user-id"), Some("1"));
+        let element = div().data("data-x", "y").unwrap();
+        assert_eq!(element.attributes().get("data-x"), Some("y"));
+        let element = div().data_json("config", &vec![1, 2]).unwrap();
+        assert_eq!(element.attributes().get("data-config"), Some("[1,2]"));
+
+        assert!(matches!(
+            div().data("userId", 1),
+            Err(DataError::InvalidKey(key)) if key == "userId"
+        ));
+        assert!(div().data("xml-thing", 1).is_err());
+    }
+
+    #[test]
+    fn class_names_split_ascii_whitespace() {
+        assert_eq!(
+            crate::classes!["a\u{a0}b c", "c\td", None::<&str>, Some("e") => false],
+            "a\u{a0}b c d"
+        );
+        let mut attributes = Attributes::default();
+        attributes.add_class("x\u{a0}y z");
+        assert!(attributes.has_class("x\u{a0}y"));
+    }
+}
@@ -0,0 +1,211 @@
+use std::fmt::Display;
+
+use crate::element_boolean_attribute;
+use crate::node::prelude::*;
+use crate::prelude::{Button, Form, Input, InputType, Optgroup, Option, Select};
+
+pub fn form(method: FormMethod, action: impl Display) -> Form {
+    Form::new_empty()
+        .attribute("method", method)
+        .attribute("action", action)
+}
+
+/// The methods a browser can submit a form with.
+///
+/// There are no `Put` or `Delete` variants because browsers treat any other `method` as `get`,
+/// so such a form would silently be sent as a `GET` with its fields in the URL. Send those
+/// requests with htmx instead, using an `hx-put` or `hx-delete` attribute.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FormMethod {
+    Get,
+    Post,
+    Dialog,
+}
+
+impl Display for FormMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Get => write!(f, "get"),
+            Self::Post => write!(f, "post"),
+            Self::Dialog => write!(f, "dialog"),
+        }
+    }
+}
+
+impl Button {
+    pub fn onclick(self, event: impl Display) -> Self {
+        self.attribute("onclick", event)
+    }
+}
+
+impl Input {
+    pub fn r#type(self, value: InputType) -> Self {
+        self.attribute("type", value)
+    }
+}
+
+/// See also the constructors for specific types, such as [`text_input`](crate::prelude::text_input)
+/// and [`checkbox`](crate::prelude::checkbox), which only have the attributes valid for the type.
+pub fn input(input_type: InputType, name: impl Display) -> Input {
+    Input::new_empty().r#type(input_type).name(name)
+}
+
+impl Select {
+    pub fn option(self, text: impl Display, value: impl Display) -> Self {
+        self.child(option(text, value))
+    }
+
+    pub fn options(self, options: impl IntoIterator<Item = impl OptionValue>) -> Self {
+        self.children(options_with(options, &[]))
+    }
+
+    /// Adds options, selecting the one whose value is `selected`.
+    pub fn options_selected(
+        self,
+        options: impl IntoIterator<Item = impl OptionValue>,
+        selected: impl Display,
+    ) -> Self {
+        self.children(options_with(options, &[selected.to_string()]))
+    }
+
+    /// Makes this a `multiple` select and adds options, selecting those whose value is in
+    /// `selected`.
+    pub fn options_multiple(
+        self,
+        options: impl IntoIterator<Item = impl OptionValue>,
+        selected: impl IntoIterator<Item = impl Display>,
+    ) -> Self {
+        let selected = selected
+            .into_iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<_>>();
+        self.attribute("multiple", "multiple")
+            .children(options_with(options, &selected))
+    }
+
+    /// Adds an option with an empty value before the others, shown until an option is chosen
+    /// and rejected by `required`.
+    pub fn placeholder(mut self, text: impl Display) -> Self {
+        self.element.children.insert(0, option(text, "").into());
+        self
+    }
+}
+
+/// The text and value of an option, see [`Select::options`]. `(text, value)` pairs implement it.
+pub trait OptionValue {
+    fn text(&self) -> String;
+
+    fn value(&self) -> String;
+}
+
+impl<T: Display, V: Display> OptionValue for (T, V) {
+    fn text(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn value(&self) -> String {
+        self.1.to_string()
+    }
+}
+
+fn options_with(
+    options: impl IntoIterator<Item = impl OptionValue>,
+    selected: &[String],
+) -> Vec<Option> {
+    options
+        .into_iter()
+        .map(|item| {
+            let value = item.value();
+            option(item.text(), &value).set_selected(selected.contains(&value))
+        })
+        .collect()
+}
+
+pub fn select(id: impl Display, name: impl Display) -> Select {
+    Select::new_empty().id(&id).name(&name)
+}
+
+impl Optgroup {
+    pub fn options(self, options: impl IntoIterator<Item = impl OptionValue>) -> Self {
+        self.children(options_with(options, &[]))
+    }
+
+    /// Adds options, selecting the one whose value is `selected`.
+    pub fn options_selected(
+        self,
+        options: impl IntoIterator<Item = impl OptionValue>,
+        selected: impl Display,
+    ) -> Self {
+        self.children(options_with(options, &[selected.to_string()]))
+    }
+}
+
+pub fn optgroup(label: impl Display) -> Optgroup {
+    Optgroup::new_empty().label(label)
+}
+
+element_boolean_attribute!(
+    Option,
+    selected,
+    set_selected,
+    "selected",
+    "Whether the option is initially selected."
+);
+
+pub fn option(text: impl Display, value: impl Display) -> Option {
+    Option::new_empty().text(text).attribute("value", value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The values of the selected options in a rendered tree.
+    fn selected(node: impl Into<Node>) -> Vec<String> {
+        let node = node.into();
+        node.select("option[selected]")
+            .into_iter()
+            .map(|option| {
+                option
+                    .attributes()
+                    .unwrap()
+                    .get("value")
+                    .unwrap()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn select_single() {
+        let sizes = [("Small", "s"), ("Medium", "m"), ("Large", "l")];
+        let select = select("size", "size").options_selected(sizes, "m");
+        assert_eq!(select.attributes().get("multiple"), None);
+        assert!(select
+            .to_string()
+            .contains(r#"<option value="s">Small</option>"#));
+        assert_eq!(selected(select), ["m"]);
+    }
+
+    #[test]
+    fn select_multiple() {
+        let sizes = [("Small", "s"), ("Medium", "m"), ("Large", "l")];
+        let select = select("size", "size").options_multiple(sizes, ["s", "l"]);
+        assert_eq!(select.attributes().get("multiple"), Some("multiple"));
+        assert_eq!(selected(select), ["s", "l"]);
+    }
+
+    #[test]
+    fn select_optgroups() {
+        let select = select("size", "size")
+            .child(optgroup("Small").options_selected([("XS", "xs"), ("S", "s")], "s"))
+            .child(optgroup("Large").options([("L", "l")]));
+        assert_eq!(selected(select.clone()), ["s"]);
+        assert_eq!(
+            Node::from(select)
+                .select("optgroup[label=Large] option")
+                .len(),
+            1
+        );
+    }
+}
@@ -0,0 +1,382 @@
+use crate::{
+    forms::FormState,
+    prelude::{input, InputType},
+    visit::Fold,
+    GenericElement, Node,
+};
+use hmac::{Hmac, Mac};
+use hyper::{Method, Request};
+use sha2::Sha256;
+use std::{
+    collections::hash_map::RandomState,
+    fmt::Display,
+    hash::{BuildHasher, Hasher},
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Issues and verifies CSRF tokens signed with HMAC-SHA256.
+///
+/// Tokens are bound to a session identifier and expire after [`Csrf::max_age`]. Issue a token
+/// for each render with [`Csrf::token`], add it to the page with [`CsrfToken::inject`], and
+/// check incoming requests with [`Csrf::verify`].
+#[derive(Clone)]
+pub struct Csrf {
+    key: Vec<u8>,
+    field_name: String,
+    header_name: String,
+    max_age: Duration,
+}
+
+impl Csrf {
+    /// # Panics
+    /// If the secret is shorter than 32 bytes.
+    pub fn new(secret: impl AsRef<[u8]>) -> Self {
+        let key = secret.as_ref().to_vec();
+        assert!(key.len() >= 32, "CSRF secret must be at least 32 bytes");
+        Self {
+            key,
+            field_name: "csrf_token".to_string(),
+            header_name: "X-CSRF-Token".to_string(),
+            max_age: Duration::from_secs(60 * 60 * 12),
+        }
+    }
+
+    /// The name of the hidden form field, `csrf_token` by default.
+    pub fn field_name(mut self, name: impl Display) -> Self {
+        self.field_name = name.to_string();
+        self
+    }
+
+    /// The header set on htmx requests, `X-CSRF-Token` by default.
+    pub fn header_name(mut self, name: impl Display) -> Self {
+        self.header_name = name.to_string();
+        self
+    }
+
+    /// How long tokens are accepted after being issued, 12 hours by default.
+    pub fn max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = max_age;
+        self
+    }
+
+    /// Issues a new token for a session.
+    pub fn token(&self, session: impl AsRef<[u8]>) -> CsrfToken {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_u64(COUNTER.fetch_add(1, Ordering::Relaxed));
+        let nonce = hasher.finish();
+
+        let issued = now();
+        let signature = self
+            .mac(session.as_ref(), issued, nonce)
+            .finalize()
+            .into_bytes();
+        CsrfToken {
+            value: format!("{issued:x}.{nonce:016x}.{}", hex(&signature)),
+            field_name: self.field_name.clone(),
+            header_name: self.header_name.clone(),
+        }
+    }
+
+    /// Checks a token issued for a session.
+    pub fn verify_token(&self, session: impl AsRef<[u8]>, token: &str) -> Result<(), CsrfError> {
+        let mut parts = token.trim().split('.');
+        let (Some(issued), Some(nonce), Some(signature), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(CsrfError::Invalid);
+        };
+        let issued = u64::from_str_radix(issued, 16).map_err(|_| CsrfError::Invalid)?;
+        let nonce = u64::from_str_radix(nonce, 16).map_err(|_| CsrfError::Invalid)?;
+        let signature = unhex(signature).ok_or(CsrfError::Invalid)?;
+
+        self.mac(session.as_ref(), issued, nonce)
+            .verify_slice(&signature)
+            .map_err(|_| CsrfError::Invalid)?;
+        if now().saturating_sub(issued) > self.max_age.as_secs() {
+            return Err(CsrfError::Expired);
+        }
+        Ok(())
+    }
+
+    /// Checks the token of a request that can change state.
+    ///
+    /// `GET`, `HEAD`, `OPTIONS` and `TRACE` requests are always accepted. Otherwise the token is
+    /// taken from the header, as sent by htmx, or from the submitted `form` if there is one.
+    pub fn verify<B>(
+        &self,
+        session: impl AsRef<[u8]>,
+        request: &Request<B>,
+        form: Option<&FormState>,
+    ) -> Result<(), CsrfError> {
+        if matches!(
+            *request.method(),
+            Method::GET | Method::HEAD | Method::OPTIONS | Method::TRACE
+        ) {
+            return Ok(());
+        }
+
+        let header = request
+            .headers()
+            .get(&self.header_name)
+            .and_then(|value| value.to_str().ok());
+        let field = form.and_then(|form| form.value(&self.field_name));
+        match header.or(field) {
+            Some(token) => self.verify_token(session, token),
+            None => Err(CsrfError::Missing),
+        }
+    }
+
+    fn mac(&self, session: &[u8], issued: u64, nonce: u64) -> Hmac<Sha256> {
+        let mut mac =
+            Hmac::<Sha256>::new_from_slice(&self.key).expect("HMAC accepts keys of any length");
+        mac.update(&issued.to_be_bytes());
+        mac.update(&nonce.to_be_bytes());
+        mac.update(session);
+        mac
+    }
+}
+
+/// The secret is left out.
+impl std::fmt::Debug for Csrf {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Csrf")
+            .field("field_name", &self.field_name)
+            .field("header_name", &self.header_name)
+            .field("max_age", &self.max_age)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CsrfError {
+    Missing,
+    Invalid,
+    Expired,
+}
+
+impl Display for CsrfError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Missing => write!(f, "missing CSRF token"),
+            Self::Invalid => write!(f, "invalid CSRF token"),
+            Self::Expired => write!(f, "expired CSRF token"),
+        }
+    }
+}
+
+impl std::error::Error for CsrfError {}
+
+/// A token issued by [`Csrf::token`] for one render.
+///
+/// As a [`Fold`] it appends a hidden input with the token to `post`, `put`, `patch` and
+/// `delete` forms that don't have one, and adds the token header to `hx-headers` on `body` so
+/// htmx sends it with every request.
+#[derive(Debug, Clone)]
+pub struct CsrfToken {
+    value: String,
+    field_name: String,
+    header_name: String,
+}
+
+impl CsrfToken {
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// The hidden input, for forms added outside the injected page.
+    pub fn input(&self) -> Node {
+        input(InputType::Hidden, &self.field_name)
+            .value(&self.value)
+            .into()
+    }
+
+    /// Adds the token to the forms and `body` in `node`.
+    pub fn inject(&self, node: impl Into<Node>) -> Node {
+        let mut nodes = node.into().fold(&mut self.clone());
+        match nodes.len() {
+            1 => nodes.remove(0),
+            _ => Node::Fragment(nodes),
+        }
+    }
+
+    fn has_input(&self, node: &Node) -> bool {
+        let is_input = matches!(node, Node::VoidElement(element)
+            if element.tag_name == "input"
+                && element.attributes.get("name") == Some(self.field_name.as_str()));
+        is_input || node.children().iter().any(|child| self.has_input(child))
+    }
+}
+
+impl Fold for CsrfToken {
+    fn fold_element(&mut self, mut element: GenericElement) -> Vec<Node> {
+        match element.tag_name.as_str() {
+            "form" => {
+                let method = element.attributes.get("method").unwrap_or("get");
+                let unsafe_method = ["post", "put", "patch", "delete"]
+                    .iter()
+                    .any(|unsafe_method| method.eq_ignore_ascii_case(unsafe_method));
+                if unsafe_method && !element.children.iter().any(|child| self.has_input(child)) {
+                    element.push_child(self.input());
+                }
+            }
+            "body" => {
+                let headers = element.attributes.get("hx-headers").unwrap_or("{}");
+                // `js:` headers are evaluated by htmx and can't be merged.
+                if let Ok(serde_json::Value::Object(mut headers)) =
+                    serde_json::from_str::<serde_json::Value>(headers)
+                {
+                    headers.insert(self.header_name.clone(), self.value.clone().into());
+                    element.attributes.0.insert(
+                        "hx-headers".to_string(),
+                        serde_json::Value::Object(headers).to_string(),
+                    );
+                }
+            }
+            _ => {}
+        }
+        vec![element.into()]
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system time is before the Unix epoch")
+        .as_secs()
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn unhex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) || !hex.is_ascii() {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|index| u8::from_str_radix(&hex[index..index + 2], 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{body, form, Children, FormMethod};
+
+    const SECRET: &[u8; 32] = b"0123456789abcdef0123456789abcdef";
+
+    #[test]
+    fn verifies_issued_tokens() {
+        let csrf = Csrf::new(SECRET);
+        let token = csrf.token("session");
+        assert_eq!(csrf.verify_token("session", token.value()), Ok(()));
+        assert_ne!(csrf.token("session").value(), token.value());
+    }
+
+    #[test]
+    fn rejects_tampered_tokens() {
+        let csrf = Csrf::new(SECRET);
+        let token = csrf.token("session").value().to_string();
+
+        let last = if token.ends_with('0') { '1' } else { '0' };
+        let tampered = format!("{}{last}", &token[..token.len() - 1]);
+        assert_eq!(
+            csrf.verify_token("session", &tampered),
+            Err(CsrfError::Invalid)
+        );
+
+        let (issued, rest) = token.split_once('.').unwrap();
+        let issued = u64::from_str_radix(issued, 16).unwrap() + 1;
+        let tampered = format!("{issued:x}.{rest}");
+        assert_eq!(
+            csrf.verify_token("session", &tampered),
+            Err(CsrfError::Invalid)
+        );
+
+        for malformed in ["", "a.b", "a.b.c.d", "zz.00.00"] {
+            assert_eq!(
+                csrf.verify_token("session", malformed),
+                Err(CsrfError::Invalid)
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_other_keys_and_sessions() {
+        let csrf = Csrf::new(SECRET);
+        let token = csrf.token("session");
+        assert_eq!(
+            csrf.verify_token("other", token.value()),
+            Err(CsrfError::Invalid)
+        );
+
+        let other = Csrf::new(b"fedcba9876543210fedcba9876543210");
+        assert_eq!(
+            other.verify_token("session", token.value()),
+            Err(CsrfError::Invalid)
+        );
+    }
+
+    #[test]
+    fn rejects_expired_tokens() {
+        let csrf = Csrf::new(SECRET).max_age(Duration::from_secs(60));
+        let issued = now() - 61;
+        let signature = csrf.mac(b"session", issued, 7).finalize().into_bytes();
+        let token = format!("{issued:x}.{:016x}.{}", 7, hex(&signature));
+        assert_eq!(
+            csrf.verify_token("session", &token),
+            Err(CsrfError::Expired)
+        );
+    }
+
+    #[test]
+    fn verifies_requests() {
+        let csrf = Csrf::new(SECRET);
+        let token = csrf.token("session");
+        let request = |method: Method, header: Option<&str>| {
+            let mut request = Request::builder().method(method).uri("/");
+            if let Some(header) = header {
+                request = request.header("X-CSRF-Token", header);
+            }
+            request.body(()).unwrap()
+        };
+
+        assert_eq!(
+            csrf.verify("session", &request(Method::GET, None), None),
+            Ok(())
+        );
+        let post = request(Method::POST, None);
+        assert_eq!(csrf.verify("session", &post, None), Err(CsrfError::Missing));
+        let form = FormState::parse(format!("csrf_token={}", token.value()).as_bytes());
+        assert_eq!(csrf.verify("session", &post, Some(&form)), Ok(()));
+        let post = request(Method::POST, Some(token.value()));
+        assert_eq!(csrf.verify("session", &post, None), Ok(()));
+    }
+
+    #[test]
+    fn injects_hidden_inputs() {
+        let token = Csrf::new(SECRET).token("session");
+        let page = token.inject(
+            body()
+                .child(form(FormMethod::Post, "save"))
+                .child(form(FormMethod::Get, "search"))
+                .child(form(FormMethod::Post, "again").child(token.input())),
+        );
+
+        let inputs = page.select("form input[type=hidden]");
+        assert_eq!(inputs.len(), 2);
+        for input in &inputs {
+            let attributes = input.attributes().unwrap();
+            assert_eq!(attributes.get("name"), Some("csrf_token"));
+            assert_eq!(attributes.get("value"), Some(token.value()));
+        }
+        assert!(page.select("form[method=get] input").is_empty());
+
+        let headers = page.attributes().unwrap().get("hx-headers").unwrap();
+        let headers: serde_json::Value = serde_json::from_str(headers).unwrap();
+        assert_eq!(headers["X-CSRF-Token"], token.value());
+    }
+}
@@ -0,0 +1,144 @@
+use std::fmt::Display;
+
+use crate::element_boolean_attribute;
+use crate::element_struct;
+use crate::is_valid_custom_element_name;
+use crate::node::prelude::*;
+use crate::prelude::{Slot, Template};
+
+element_struct!(
+    CustomElement,
+    "",
+    "An autonomous custom element, see [`custom_element`]. Use [`custom_element!`](crate::custom_element) for typed attributes."
+);
+
+/// The error returned by [`custom_element`] for a name that isn't a valid custom element name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidCustomElementName(pub String);
+
+impl Display for InvalidCustomElementName {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "invalid custom element name: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidCustomElementName {}
+
+/// An element with a tag name chosen at runtime, which must be a valid custom element name, see
+/// [`is_valid_custom_element_name`].
+pub fn custom_element(tag_name: impl Display) -> Result<CustomElement, InvalidCustomElementName> {
+    let tag_name = tag_name.to_string();
+    if !is_valid_custom_element_name(&tag_name) {
+        return Err(InvalidCustomElementName(tag_name));
+    }
+    let mut element = CustomElement::new_empty();
+    element.element.tag_name = tag_name;
+    Ok(element)
+}
+
+impl CustomElement {
+    /// Adds a declarative shadow root, see [`shadow_root`](crate::prelude::shadow_root).
+    pub fn shadow_root(mut self, root: Template) -> Self {
+        self.element.children.insert(0, root.into());
+        self
+    }
+}
+
+element_boolean_attribute!(
+    Template,
+    shadowrootdelegatesfocus,
+    set_shadowrootdelegatesfocus,
+    "shadowrootdelegatesfocus",
+    "Delegates focus from the shadow host to the first focusable element in the shadow root."
+);
+element_boolean_attribute!(
+    Template,
+    shadowrootclonable,
+    set_shadowrootclonable,
+    "shadowrootclonable",
+    "Lets the shadow root be cloned with its host by `cloneNode` and `importNode`."
+);
+element_boolean_attribute!(
+    Template,
+    shadowrootserializable,
+    set_shadowrootserializable,
+    "shadowrootserializable",
+    "Lets the shadow root be serialized by `getHTML`."
+);
+
+impl Template {
+    /// Makes the template a declarative shadow root of its parent.
+    pub fn shadowrootmode(self, mode: crate::ShadowRootMode) -> Self {
+        self.attribute("shadowrootmode", mode)
+    }
+}
+
+pub fn template() -> Template {
+    Template::new_empty()
+}
+
+/// A declarative shadow root, attached to its parent element when parsed.
+pub fn shadow_root(mode: crate::ShadowRootMode) -> Template {
+    template().shadowrootmode(mode)
+}
+
+pub fn slot() -> Slot {
+    Slot::new_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{shadow_root, span, Children};
+    use crate::ShadowRootMode;
+
+    #[test]
+    fn validates_names() {
+        for name in ["star-rating", "x-", "my-element.v2", "math-α", "a_b-c"] {
+            assert!(is_valid_custom_element_name(name), "{name}");
+            assert!(custom_element(name).is_ok(), "{name}");
+        }
+        for name in [
+            "",
+            "rating",
+            "Star-rating",
+            "star-Rating",
+            "1-star",
+            "-star",
+            "star rating-x",
+            "font-face",
+            "annotation-xml",
+        ] {
+            assert!(!is_valid_custom_element_name(name), "{name}");
+            assert_eq!(
+                custom_element(name).unwrap_err(),
+                InvalidCustomElementName(name.to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn shadow_root_comes_first() {
+        let element = custom_element("star-rating")
+            .unwrap()
+            .child(span())
+            .shadow_root(shadow_root(ShadowRootMode::Open));
+        assert_eq!(
+            element.to_string(),
+            r#"<star-rating><template shadowrootmode="open"></template><span></span></star-rating>"#
+        );
+    }
+
+    crate::custom_element!(StarRating, "star-rating", "A rating out of five stars.");
+
+    #[test]
+    fn macro_elements() {
+        let element = StarRating::new()
+            .child(span())
+            .shadow_root(shadow_root(ShadowRootMode::Closed));
+        assert_eq!(
+            element.to_string(),
+            r#"<star-rating><template shadowrootmode="closed"></template><span></span></star-rating>"#
+        );
+    }
+}
@@ -0,0 +1,546 @@
+use crate::{Attributes, Node};
+use serde::{Serialize, Serializer};
+use std::collections::BTreeSet;
+
+/// An element-level change produced by [`diff`].
+///
+/// Paths are child indices from the root, with an empty path being the root itself. Patches
+/// must be applied in order, as each path refers to the tree left by the patches before it.
+///
+/// Indices count [`Node`] children, which only line up with a browser's `childNodes` once both
+/// trees have been [`normalize`]d: browsers merge adjacent text nodes and drop empty ones, and
+/// there are no fragments in the DOM. [`Node::RawText`] is counted as one node, so it shouldn't
+/// contain markup or sit next to other text.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum Patch {
+    InsertChild {
+        path: Vec<usize>,
+        #[serde(serialize_with = "html")]
+        node: Node,
+    },
+    RemoveChild {
+        path: Vec<usize>,
+    },
+    /// Moves the child at `path` to `index` among its siblings, counted after removing it.
+    MoveChild {
+        path: Vec<usize>,
+        index: usize,
+    },
+    ReplaceChild {
+        path: Vec<usize>,
+        #[serde(serialize_with = "html")]
+        node: Node,
+    },
+    SetAttribute {
+        path: Vec<usize>,
+        name: String,
+        value: String,
+    },
+    RemoveAttribute {
+        path: Vec<usize>,
+        name: String,
+    },
+    SetText {
+        path: Vec<usize>,
+        text: String,
+    },
+}
+
+fn html<S: Serializer>(node: &Node, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(node)
+}
+
+impl Patch {
+    pub fn path(&self) -> &[usize] {
+        match self {
+            Self::InsertChild { path, .. }
+            | Self::RemoveChild { path }
+            | Self::MoveChild { path, .. }
+            | Self::ReplaceChild { path, .. }
+            | Self::SetAttribute { path, .. }
+            | Self::RemoveAttribute { path, .. }
+            | Self::SetText { path, .. } => path,
+        }
+    }
+}
+
+/// Computes the patches that turn `old` into `new`.
+///
+/// Children are matched by their `key` attribute, or `id` if there is none, so that keyed
+/// elements are updated in place when siblings are inserted or removed, and moved rather than
+/// recreated when reordered. Unkeyed children are matched by position and tag name.
+pub fn diff(old: &Node, new: &Node) -> Vec<Patch> {
+    let mut patches = Vec::new();
+    diff_node(old, new, &mut Vec::new(), &mut patches);
+    patches
+}
+
+/// Applies patches from [`diff`] in order.
+///
+/// # Panics
+/// If a patch's path doesn't exist in the tree, e.g. when applied to a tree other than the one
+/// it was computed from.
+pub fn apply(root: &mut Node, patches: &[Patch]) {
+    for patch in patches {
+        match patch {
+            Patch::InsertChild { path, node } => {
+                let (index, parent) = split_path(path);
+                children_mut(root, parent).insert(index, node.clone());
+            }
+            Patch::RemoveChild { path } => {
+                let (index, parent) = split_path(path);
+                children_mut(root, parent).remove(index);
+            }
+            Patch::MoveChild { path, index: to } => {
+                let (from, parent) = split_path(path);
+                let children = children_mut(root, parent);
+                let node = children.remove(from);
+                children.insert(*to, node);
+            }
+            Patch::ReplaceChild { path, node } => *node_mut(root, path) = node.clone(),
+            Patch::SetAttribute { path, name, value } => {
+                attributes_mut(root, path)
+                    .0
+                    .insert(name.clone(), value.clone());
+            }
+            Patch::RemoveAttribute { path, name } => {
+                attributes_mut(root, path).remove(name);
+            }
+            Patch::SetText { path, text } => *node_mut(root, path) = Node::Text(text.clone()),
+        }
+    }
+}
+
+/// Merges adjacent text nodes, removes empty ones and flattens nested fragments, so that
+/// [`Patch`] paths match the DOM the tree renders to.
+pub fn normalize(node: &mut Node) {
+    let children = match node {
+        Node::Element(element) => &mut element.children,
+        Node::Fragment(nodes) => nodes,
+        _ => return,
+    };
+
+    let mut normalized: Vec<Node> = Vec::with_capacity(children.len());
+    let mut pending = std::mem::take(children);
+    pending.reverse();
+    while let Some(mut child) = pending.pop() {
+        match (&mut child, normalized.last_mut()) {
+            (Node::Fragment(nodes), _) => pending.extend(std::mem::take(nodes).into_iter().rev()),
+            (Node::Text(text), _) if text.is_empty() => {}
+            (Node::Text(text), Some(Node::Text(previous))) => previous.push_str(text),
+            _ => {
+                normalize(&mut child);
+                normalized.push(child);
+            }
+        }
+    }
+    *children = normalized;
+}
+
+/// Serializes patches as a JSON array of objects tagged by `op`, with nodes as HTML strings.
+pub fn to_json(patches: &[Patch]) -> String {
+    serde_json::to_string(patches).expect("failed to serialize patches")
+}
+
+/// Renders the changed parts of `new` as htmx out-of-band swaps.
+///
+/// Each change is covered by swapping its closest ancestor (or itself) with an `id`, and
+/// swaps nested inside another are dropped. Returns `None` if a change has no such element.
+pub fn to_oob_swaps(new: &Node, patches: &[Patch]) -> Option<String> {
+    let mut targets = BTreeSet::new();
+    for patch in patches {
+        let path = patch.path();
+        let target = match patch {
+            Patch::SetAttribute { .. } | Patch::RemoveAttribute { .. } => path,
+            _ => &path[..path.len().saturating_sub(1)],
+        };
+        targets.insert(closest_with_id(new, target)?);
+    }
+
+    let mut swaps = String::new();
+    let mut previous: Option<&[usize]> = None;
+    for target in &targets {
+        if previous.is_some_and(|previous| target.starts_with(previous)) {
+            continue;
+        }
+        previous = Some(target);
+
+        let mut node = node_at(new, target).clone();
+        if let Node::Element(element) = &mut node {
+            element
+                .attributes
+                .set("hx-swap-oob".to_string(), "true".to_string());
+        } else if let Node::VoidElement(element) = &mut node {
+            element
+                .attributes
+                .set("hx-swap-oob".to_string(), "true".to_string());
+        }
+        swaps.push_str(&node.to_string());
+    }
+    Some(swaps)
+}
+
+fn closest_with_id(root: &Node, path: &[usize]) -> Option<Vec<usize>> {
+    (0..=path.len())
+        .rev()
+        .map(|len| &path[..len])
+        .find_map(|path| {
+            node_at(root, path)
+                .attributes()?
+                .get("id")
+                .map(|_| path.to_vec())
+        })
+}
+
+fn split_path(path: &[usize]) -> (usize, &[usize]) {
+    let (index, parent) = path.split_last().expect("cannot insert or remove the root");
+    (*index, parent)
+}
+
+fn node_at<'a>(root: &'a Node, path: &[usize]) -> &'a Node {
+    path.iter()
+        .fold(root, |node, index| &node.children()[*index])
+}
+
+fn node_mut<'a>(root: &'a mut Node, path: &[usize]) -> &'a mut Node {
+    path.iter()
+        .fold(root, |node, index| &mut children_mut(node, &[])[*index])
+}
+
+fn children_mut<'a>(root: &'a mut Node, path: &[usize]) -> &'a mut Vec<Node> {
+    match node_mut(root, path) {
+        Node::Element(element) => &mut element.children,
+        Node::Fragment(nodes) => nodes,
+        _ => panic!("node at {path:?} cannot have children"),
+    }
+}
+
+fn attributes_mut<'a>(root: &'a mut Node, path: &[usize]) -> &'a mut Attributes {
+    match node_mut(root, path) {
+        Node::Element(element) => &mut element.attributes,
+        Node::VoidElement(element) => &mut element.attributes,
+        _ => panic!("node at {path:?} has no attributes"),
+    }
+}
+
+/// The identity of a keyed element.
+fn key(node: &Node) -> Option<(&str, &str)> {
+    let attributes = node.attributes()?;
+    let key = attributes.get("key").or_else(|| attributes.get("id"))?;
+    Some((node.tag_name()?, key))
+}
+
+fn diff_node(old: &Node, new: &Node, path: &mut Vec<usize>, patches: &mut Vec<Patch>) {
+    match (old, new) {
+        (Node::Element(old), Node::Element(new)) if old.tag_name == new.tag_name => {
+            diff_attributes(&old.attributes, &new.attributes, path, patches);
+            diff_children(&old.children, &new.children, path, patches);
+        }
+        (Node::VoidElement(old), Node::VoidElement(new)) if old.tag_name == new.tag_name => {
+            diff_attributes(&old.attributes, &new.attributes, path, patches);
+        }
+        (Node::Text(old), Node::Text(new)) if old == new => {}
+        (Node::RawText(old), Node::RawText(new)) if old == new => {}
+        (Node::Comment(old), Node::Comment(new)) if old == new => {}
+        (Node::Doctype(old), Node::Doctype(new)) if old == new => {}
+        (Node::Fragment(old), Node::Fragment(new)) => diff_children(old, new, path, patches),
+        (Node::Text(_), Node::Text(new)) => patches.push(Patch::SetText {
+            path: path.clone(),
+            text: new.clone(),
+        }),
+        _ => patches.push(Patch::ReplaceChild {
+            path: path.clone(),
+            node: new.clone(),
+        }),
+    }
+}
+
+fn diff_attributes(old: &Attributes, new: &Attributes, path: &[usize], patches: &mut Vec<Patch>) {
+    let mut removed = old
+        .0
+        .keys()
+        .filter(|name| !new.0.contains_key(*name))
+        .collect::<Vec<_>>();
+    removed.sort();
+    for name in removed {
+        patches.push(Patch::RemoveAttribute {
+            path: path.to_vec(),
+            name: name.clone(),
+        });
+    }
+
+    let mut changed = new
+        .0
+        .iter()
+        .filter(|(name, value)| old.get(name) != Some(value.as_str()))
+        .collect::<Vec<_>>();
+    changed.sort();
+    for (name, value) in changed {
+        patches.push(Patch::SetAttribute {
+            path: path.to_vec(),
+            name: name.clone(),
+            value: value.clone(),
+        });
+    }
+}
+
+fn diff_children(old: &[Node], new: &[Node], path: &mut Vec<usize>, patches: &mut Vec<Patch>) {
+    // The old children in their current order as patches are applied.
+    let mut current = old.iter().collect::<Vec<_>>();
+    let wanted = |key: (&str, &str), from: usize| {
+        new[from..].iter().any(|node| self::key(node) == Some(key))
+    };
+
+    for (index, node) in new.iter().enumerate() {
+        // Remove keyed children that are gone, rather than inserting before them.
+        while let Some(existing) = current.get(index) {
+            match key(existing) {
+                Some(existing) if !wanted(existing, index) => {
+                    current.remove(index);
+                    push_child(patches, path, index, |path| Patch::RemoveChild { path });
+                }
+                _ => break,
+            }
+        }
+
+        let matched = match key(node) {
+            Some(key) => current[index..]
+                .iter()
+                .position(|existing| self::key(existing) == Some(key))
+                .map(|offset| index + offset),
+            None => current
+                .get(index)
+                .filter(|existing| key(existing).is_none())
+                .map(|_| index),
+        };
+
+        match matched {
+            Some(matched) if matched == index => {
+                path.push(index);
+                diff_node(current[index], node, path, patches);
+                path.pop();
+            }
+            Some(matched) => {
+                let existing = current.remove(matched);
+                current.insert(index, existing);
+                push_child(patches, path, matched, |path| Patch::MoveChild {
+                    path,
+                    index,
+                });
+                path.push(index);
+                diff_node(existing, node, path, patches);
+                path.pop();
+            }
+            None => {
+                current.insert(index, node);
+                push_child(patches, path, index, |path| Patch::InsertChild {
+                    path,
+                    node: node.clone(),
+                });
+            }
+        }
+    }
+
+    for index in (new.len()..current.len()).rev() {
+        push_child(patches, path, index, |path| Patch::RemoveChild { path });
+    }
+}
+
+fn push_child(
+    patches: &mut Vec<Patch>,
+    parent: &[usize],
+    index: usize,
+    patch: impl FnOnce(Vec<usize>) -> Patch,
+) {
+    patches.push(patch([parent, &[index]].concat()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GenericElement, VoidElement};
+
+    /// A xorshift generator, so the generated trees are the same on every run.
+    struct Rng(u64);
+
+    impl Rng {
+        fn below(&mut self, n: usize) -> usize {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            (self.0 % n as u64) as usize
+        }
+
+        fn pick<'a>(&mut self, items: &[&'a str]) -> &'a str {
+            items[self.below(items.len())]
+        }
+    }
+
+    fn element(tag_name: &str, attributes: &[(&str, &str)], children: Vec<Node>) -> Node {
+        let mut element = GenericElement {
+            tag_name: tag_name.to_string(),
+            attributes: Attributes::default(),
+            children,
+        };
+        for (name, value) in attributes {
+            element.attributes.set(name.to_string(), value.to_string());
+        }
+        Node::Element(element)
+    }
+
+    fn tree(rng: &mut Rng, depth: usize) -> Node {
+        let mut attributes = Vec::new();
+        for name in ["class", "title"] {
+            if rng.below(2) == 0 {
+                attributes.push((name, rng.pick(&["a", "b"])));
+            }
+        }
+
+        let mut children = Vec::new();
+        let mut keys = vec!["k0", "k1", "k2", "k3"];
+        for _ in 0..if depth == 0 { 0 } else { rng.below(5) } {
+            children.push(match rng.below(6) {
+                0 => Node::Text(rng.pick(&["", "x", "y"]).to_string()),
+                1 => Node::Comment(rng.pick(&["c", "d"]).to_string()),
+                2 => Node::VoidElement(VoidElement {
+                    tag_name: rng.pick(&["br", "hr"]).to_string(),
+                    attributes: Attributes::default(),
+                }),
+                3 if !keys.is_empty() => {
+                    let key = keys.remove(rng.below(keys.len()));
+                    let Node::Element(mut child) = tree(rng, depth - 1) else {
+                        unreachable!()
+                    };
+                    child.attributes.set("key".to_string(), key.to_string());
+                    Node::Element(child)
+                }
+                _ => tree(rng, depth - 1),
+            });
+        }
+
+        element(rng.pick(&["div", "p"]), &attributes, children)
+    }
+
+    /// Reorders keyed siblings throughout the tree.
+    fn shuffle(rng: &mut Rng, node: &mut Node) {
+        if let Node::Element(element) = node {
+            for index in (1..element.children.len()).rev() {
+                let other = rng.below(index + 1);
+                element.children.swap(index, other);
+            }
+            for child in &mut element.children {
+                shuffle(rng, child);
+            }
+        }
+    }
+
+    /// Renders a tree with sorted attributes, which `Display` leaves in hash map order.
+    fn canonical(node: &Node) -> String {
+        let attributes = node.attributes().map(|attributes| {
+            let mut attributes = attributes.0.iter().collect::<Vec<_>>();
+            attributes.sort();
+            attributes
+        });
+        let children = node.children().iter().map(canonical).collect::<String>();
+        match node {
+            Node::Element(element) => format!("<{} {attributes:?}>{children}</>", element.tag_name),
+            Node::VoidElement(element) => format!("<{} {attributes:?} />", element.tag_name),
+            Node::Fragment(nodes) => nodes.iter().map(canonical).collect(),
+            node => node.to_string(),
+        }
+    }
+
+    fn assert_applies(old: &Node, new: &Node) {
+        let patches = diff(old, new);
+        let mut patched = old.clone();
+        apply(&mut patched, &patches);
+        assert_eq!(canonical(&patched), canonical(new), "{patches:#?}");
+    }
+
+    #[test]
+    fn apply_diff_renders_new_tree() {
+        let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+        for _ in 0..1000 {
+            let old = tree(&mut rng, 3);
+            let new = tree(&mut rng, 3);
+            assert_applies(&old, &new);
+
+            let mut shuffled = old.clone();
+            shuffle(&mut rng, &mut shuffled);
+            assert_applies(&old, &shuffled);
+        }
+    }
+
+    #[test]
+    fn normalize_keeps_rendering() {
+        let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+        for _ in 0..1000 {
+            let old = tree(&mut rng, 3);
+            let mut normalized =
+                Node::Fragment(vec![old.clone(), Node::Fragment(vec![old.clone()])]);
+            let rendered = canonical(&normalized);
+            normalize(&mut normalized);
+            assert_eq!(canonical(&normalized), rendered);
+        }
+    }
+
+    #[test]
+    fn keyed_moves_keep_subtrees() {
+        let item = |key, class| {
+            element(
+                "li",
+                &[("key", key), ("class", class)],
+                vec![Node::Text(key.to_string())],
+            )
+        };
+        let old = element(
+            "ul",
+            &[],
+            vec![item("a", "x"), item("b", "x"), item("c", "x")],
+        );
+        let new = element(
+            "ul",
+            &[],
+            vec![item("c", "y"), item("a", "x"), item("b", "x")],
+        );
+
+        let patches = diff(&old, &new);
+        assert_eq!(
+            to_json(&patches),
+            r#"[{"op":"move_child","path":[2],"index":0},{"op":"set_attribute","path":[0],"name":"class","value":"y"}]"#
+        );
+        assert_applies(&old, &new);
+    }
+
+    #[test]
+    fn normalized_paths_match_dom() {
+        let old = element(
+            "p",
+            &[],
+            vec![
+                Node::Text("a".to_string()),
+                Node::Fragment(vec![
+                    Node::Text("b".to_string()),
+                    element("span", &[], vec![]),
+                ]),
+                Node::Text(String::new()),
+                element("em", &[], vec![]),
+            ],
+        );
+        let mut new = old.clone();
+        if let Node::Element(p) = &mut new {
+            p.children[3] = element("em", &[("class", "x")], vec![]);
+        }
+
+        // Without normalizing, the `em` is the fourth child rather than the third in the DOM.
+        assert_eq!(diff(&old, &new)[0].path(), [3]);
+
+        let (mut old, mut new) = (old, new);
+        normalize(&mut old);
+        normalize(&mut new);
+        assert_eq!(old.children().len(), 3);
+        assert!(matches!(&old.children()[0], Node::Text(text) if text == "ab"));
+        assert_eq!(diff(&old, &new)[0].path(), [2]);
+    }
+}
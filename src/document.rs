@@ -0,0 +1,21 @@
+use crate::node::prelude::*;
+use crate::prelude::Html;
+
+impl Html {
+    pub fn response(&self) -> hyper::Response<http_body_util::Full<hyper::body::Bytes>> {
+        crate::validate::debug_check(&self.element);
+
+        hyper::Response::builder()
+            .header(hyper::header::CONTENT_TYPE, "text/html; charset=utf-8")
+            .body(http_body_util::Full::new(hyper::body::Bytes::from(
+                format!("{}\n{self}", Node::doctype()),
+            )))
+            .unwrap()
+    }
+
+    pub fn response_ok<E>(
+        &self,
+    ) -> Result<hyper::Response<http_body_util::Full<hyper::body::Bytes>>, E> {
+        Ok(self.response())
+    }
+}
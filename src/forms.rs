@@ -0,0 +1,544 @@
+use crate::{
+    aria::Invalid,
+    prelude::{form, p, Form, FormMethod, OptionValue},
+    Attributes, Children, Element, GenericElement, Node,
+};
+use http_body_util::{BodyExt, Full, LengthLimitError, Limited};
+use hyper::{body::Bytes, header, Method, Request, Response, StatusCode};
+use std::{
+    collections::{HashMap, HashSet},
+    error::Error,
+    fmt::Display,
+    str::FromStr,
+};
+
+/// A type with a form control for each field, usually implemented with `#[derive(Form)]`.
+pub trait FormFields {
+    /// The labelled controls, pre-filled from `value` if there is one.
+    fn fields(value: Option<&Self>) -> Vec<Node>;
+
+    /// Parses the submitted fields, recording an error in `state` for each invalid one.
+    ///
+    /// Returns `None` if any field is invalid, the form can then be re-rendered with
+    /// [`FormState::render`].
+    fn from_state(state: &mut FormState) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// An empty form, e.g. `User::form(FormMethod::Post, "/users")`.
+    fn form(method: FormMethod, action: impl Display) -> Form {
+        form(method, action).children(Self::fields(None))
+    }
+
+    /// A form pre-filled with this value.
+    fn edit_form(&self, method: FormMethod, action: impl Display) -> Form {
+        form(method, action).children(Self::fields(Some(self)))
+    }
+}
+
+/// A type chosen from a fixed set of options, shown as a `select` by `#[derive(Form)]`.
+/// Usually implemented with `#[derive(SelectOptions)]`.
+pub trait SelectOptions: OptionValue + Sized {
+    /// Every option, in the order they are shown.
+    fn options() -> Vec<Self>;
+
+    /// The option with this value.
+    fn from_value(value: &str) -> Option<Self> {
+        Self::options()
+            .into_iter()
+            .find(|option| option.value() == value)
+    }
+}
+
+/// A submitted form, with the errors found while parsing or validating it.
+///
+/// After a failed submission the form is rendered again with [`FormState::render`] or
+/// [`FormState::response`], which fill in the submitted values and attach the errors to their
+/// controls.
+#[derive(Debug, Clone, Default)]
+pub struct FormState {
+    values: Vec<(String, String)>,
+    errors: HashMap<String, Vec<String>>,
+}
+
+/// Why [`FormState::from_request`] failed.
+#[derive(Debug)]
+pub enum FormError {
+    /// The body is larger than the limit.
+    TooLarge,
+    /// Reading the body failed.
+    Body(Box<dyn Error + Send + Sync>),
+}
+
+impl FormError {
+    /// A `413 Payload Too Large` or `400 Bad Request` response.
+    pub fn response(&self) -> Response<Full<Bytes>> {
+        let status = match self {
+            Self::TooLarge => StatusCode::PAYLOAD_TOO_LARGE,
+            Self::Body(_) => StatusCode::BAD_REQUEST,
+        };
+        Response::builder()
+            .status(status)
+            .body(Full::new(Bytes::from(self.to_string())))
+            .unwrap()
+    }
+}
+
+impl Display for FormError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::TooLarge => write!(f, "form body is too large"),
+            Self::Body(error) => write!(f, "failed to read form body: {error}"),
+        }
+    }
+}
+
+impl Error for FormError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::TooLarge => None,
+            Self::Body(error) => Some(error.as_ref()),
+        }
+    }
+}
+
+impl FormState {
+    /// The body size accepted by [`FormState::from_request`].
+    pub const MAX_BODY_SIZE: usize = 64 * 1024;
+
+    /// Parses an `application/x-www-form-urlencoded` body or query string.
+    pub fn parse(body: &[u8]) -> Self {
+        Self {
+            values: form_urlencoded::parse(body).into_owned().collect(),
+            errors: HashMap::new(),
+        }
+    }
+
+    /// Parses the query string of `GET` and `HEAD` requests and the body of any other request.
+    ///
+    /// Bodies larger than [`FormState::MAX_BODY_SIZE`] are rejected with
+    /// [`FormError::TooLarge`], use [`FormState::from_request_limited`] to change the limit.
+    pub async fn from_request<B>(request: Request<B>) -> Result<Self, FormError>
+    where
+        B: hyper::body::Body,
+        B::Error: Into<Box<dyn Error + Send + Sync>>,
+    {
+        Self::from_request_limited(request, Self::MAX_BODY_SIZE).await
+    }
+
+    /// Like [`FormState::from_request`], accepting bodies of up to `limit` bytes.
+    pub async fn from_request_limited<B>(
+        request: Request<B>,
+        limit: usize,
+    ) -> Result<Self, FormError>
+    where
+        B: hyper::body::Body,
+        B::Error: Into<Box<dyn Error + Send + Sync>>,
+    {
+        if matches!(*request.method(), Method::GET | Method::HEAD) {
+            let query = request.uri().query().unwrap_or_default();
+            return Ok(Self::parse(query.as_bytes()));
+        }
+        let body = Limited::new(request.into_body(), limit)
+            .collect()
+            .await
+            .map_err(|error| match error.downcast::<LengthLimitError>() {
+                Ok(_) => FormError::TooLarge,
+                Err(error) => FormError::Body(error),
+            })?
+            .to_bytes();
+        Ok(Self::parse(&body))
+    }
+
+    /// The first submitted value of a field.
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.values
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// All submitted values of a field, e.g. for a `select` with `multiple`.
+    pub fn values<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.values
+            .iter()
+            .filter(move |(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Removes whitespace around the submitted values of a field, before parsing it.
+    ///
+    /// Values are otherwise parsed as submitted. `#[derive(Form)]` calls this for fields marked
+    /// `#[form(trim)]`.
+    pub fn trim(&mut self, name: &str) {
+        for (key, value) in &mut self.values {
+            if key == name && value.trim().len() != value.len() {
+                *value = value.trim().to_string();
+            }
+        }
+    }
+
+    /// Parses a field, recording an error if it is missing or invalid.
+    ///
+    /// Empty values are only an error if they don't parse, use [`FormState::required`] to
+    /// reject empty strings.
+    pub fn field<T: FromStr>(&mut self, name: &str) -> Option<T> {
+        self.parse_with(name, false, |value| value.parse().ok())?
+    }
+
+    /// Parses a field, recording an error if it is missing, empty or invalid.
+    pub fn required<T: FromStr>(&mut self, name: &str) -> Option<T> {
+        self.parse_with(name, true, |value| value.parse().ok())?
+    }
+
+    /// Parses a field that may be left empty, recording an error if it is invalid.
+    pub fn optional<T: FromStr>(&mut self, name: &str) -> Option<Option<T>> {
+        self.parse_with(name, false, |value| match value {
+            "" => Some(None),
+            value => value.parse().ok().map(Some),
+        })?
+    }
+
+    /// Whether a checkbox was checked, unchecked checkboxes are not submitted.
+    pub fn checkbox(&self, name: &str) -> bool {
+        self.value(name).is_some()
+    }
+
+    /// Parses a `select`, recording an error if no valid option was chosen.
+    pub fn select<T: SelectOptions>(&mut self, name: &str) -> Option<T> {
+        self.parse_with(name, true, T::from_value)?
+    }
+
+    /// Parses a `select` with an empty option, recording an error if the option is invalid.
+    pub fn optional_select<T: SelectOptions>(&mut self, name: &str) -> Option<Option<T>> {
+        self.parse_with(name, false, |value| match value {
+            "" => Some(None),
+            value => T::from_value(value).map(Some),
+        })?
+    }
+
+    fn parse_with<T>(
+        &mut self,
+        name: &str,
+        required: bool,
+        parse: impl FnOnce(&str) -> Option<T>,
+    ) -> Option<Option<T>> {
+        let value = self.value(name).unwrap_or_default();
+        if required && value.is_empty() {
+            self.error(name, "This field is required.");
+            return None;
+        }
+        let missing = value.is_empty();
+        let parsed = parse(value);
+        if parsed.is_none() {
+            let message = if missing {
+                "This field is required."
+            } else {
+                "Enter a valid value."
+            };
+            self.error(name, message);
+        }
+        Some(parsed)
+    }
+
+    /// Records an error for a field, e.g. after checking the parsed value.
+    pub fn error(&mut self, name: impl Display, message: impl Display) {
+        self.errors
+            .entry(name.to_string())
+            .or_default()
+            .push(message.to_string());
+    }
+
+    pub fn errors(&self, name: &str) -> &[String] {
+        self.errors.get(name).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Fills the controls in `node` with the submitted values and attaches errors to them.
+    ///
+    /// Invalid controls get `aria-invalid` and are described by a `p.field-error` inserted
+    /// after them. Passwords, file inputs, hidden inputs and buttons keep their values.
+    pub fn render(&self, node: impl Into<Node>) -> Node {
+        let mut node = node.into();
+        let mut described = HashSet::new();
+        if let Some(error) = self.fill(&mut node, &mut described) {
+            node = Node::Fragment(vec![node, error]);
+        }
+        node
+    }
+
+    /// A `422 Unprocessable Entity` response with [`FormState::render`], with a doctype if
+    /// `node` is an `html` element.
+    pub fn response(&self, node: impl Into<Node>) -> Response<Full<Bytes>> {
+        let node = self.render(node);
+        let body = match node.tag_name() {
+            Some("html") => format!("{}\n{node}", Node::doctype()),
+            _ => node.to_string(),
+        };
+        Response::builder()
+            .status(StatusCode::UNPROCESSABLE_ENTITY)
+            .header(header::CONTENT_TYPE, "text/html; charset=utf-8")
+            .body(Full::new(Bytes::from(body)))
+            .unwrap()
+    }
+
+    fn fill_children(&self, children: &mut Vec<Node>, described: &mut HashSet<String>) {
+        let mut index = 0;
+        while index < children.len() {
+            if let Some(error) = self.fill(&mut children[index], described) {
+                index += 1;
+                children.insert(index, error);
+            }
+            index += 1;
+        }
+    }
+
+    /// Fills a node, returning the error message to insert after it.
+    fn fill(&self, node: &mut Node, described: &mut HashSet<String>) -> Option<Node> {
+        let attributes = match node {
+            Node::Element(element) => match element.tag_name.as_str() {
+                "textarea" | "select" => {
+                    let name = element.attributes.get("name")?.to_string();
+                    if let Some(value) = self.value(&name) {
+                        if element.tag_name == "textarea" {
+                            element.children = vec![Node::Text(value.to_string())];
+                        } else {
+                            let values = self.values(&name).collect::<Vec<_>>();
+                            select_options(&mut element.children, &values);
+                        }
+                    }
+                    &mut element.attributes
+                }
+                _ => {
+                    self.fill_children(&mut element.children, described);
+                    return None;
+                }
+            },
+            Node::VoidElement(element) if element.tag_name == "input" => {
+                let name = element.attributes.get("name")?.to_string();
+                let value = element.attributes.get("value").unwrap_or("on").to_string();
+                match element.attributes.get("type").unwrap_or("text") {
+                    "checkbox" | "radio" => {
+                        if self.values(&name).any(|submitted| submitted == value) {
+                            element
+                                .attributes
+                                .0
+                                .insert("checked".to_string(), "checked".to_string());
+                        } else {
+                            element.attributes.remove("checked");
+                        }
+                    }
+                    "password" | "file" | "hidden" | "submit" | "reset" | "button" | "image" => {}
+                    _ => {
+                        if let Some(submitted) = self.value(&name) {
+                            element
+                                .attributes
+                                .0
+                                .insert("value".to_string(), submitted.to_string());
+                        }
+                    }
+                }
+                &mut element.attributes
+            }
+            Node::Fragment(nodes) => {
+                self.fill_children(nodes, described);
+                return None;
+            }
+            _ => return None,
+        };
+        self.describe_error(attributes, described)
+    }
+
+    fn describe_error(
+        &self,
+        attributes: &mut Attributes,
+        described: &mut HashSet<String>,
+    ) -> Option<Node> {
+        let name = attributes.get("name")?.to_string();
+        let errors = self.errors(&name);
+        if errors.is_empty() {
+            return None;
+        }
+
+        let id = match attributes.get("id") {
+            Some(id) => format!("{id}-error"),
+            None => format!("{name}-error"),
+        };
+        let describedby = match attributes.get("aria-describedby") {
+            Some(existing) if existing.split_ascii_whitespace().any(|other| other == id) => {
+                existing.to_string()
+            }
+            Some(existing) => format!("{existing} {id}"),
+            None => id.clone(),
+        };
+        attributes
+            .0
+            .insert("aria-describedby".to_string(), describedby);
+        attributes
+            .0
+            .insert("aria-invalid".to_string(), Invalid::True.to_string());
+
+        // Radio buttons and checkboxes sharing a name get a single message.
+        if !described.insert(name) {
+            return None;
+        }
+        Some(p(errors.join(" ")).id(id).class("field-error").into())
+    }
+}
+
+/// Marks the options with one of the values as selected, including inside `optgroup`s.
+fn select_options(children: &mut [Node], values: &[&str]) {
+    for child in children {
+        let Node::Element(element) = child else {
+            continue;
+        };
+        match element.tag_name.as_str() {
+            "option" => {
+                let value = option_value(element);
+                if values.contains(&value.as_str()) {
+                    element
+                        .attributes
+                        .0
+                        .insert("selected".to_string(), "selected".to_string());
+                } else {
+                    element.attributes.remove("selected");
+                }
+            }
+            "optgroup" => select_options(&mut element.children, values),
+            _ => {}
+        }
+    }
+}
+
+/// The `value` attribute of an option, which defaults to its text.
+fn option_value(option: &GenericElement) -> String {
+    match option.attributes.get("value") {
+        Some(value) => value.to_string(),
+        None => option
+            .children
+            .iter()
+            .filter_map(|child| match child {
+                Node::Text(text) => Some(text.as_str()),
+                _ => None,
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Form;
+
+    #[derive(Debug, Form)]
+    struct Signup {
+        #[form(required, trim)]
+        name: String,
+        #[form(min = -5, max = 10, trim)]
+        offset: i32,
+        #[form(required)]
+        nickname: Option<String>,
+        age: Option<u8>,
+        #[form(password)]
+        password: String,
+    }
+
+    #[test]
+    fn derived_form_parses() {
+        let mut state = FormState::parse(b"name=Ann&offset=-5&nickname=A&age=&password=secret");
+        let signup = Signup::from_state(&mut state).unwrap();
+        assert!(state.is_valid());
+        assert_eq!(signup.name, "Ann");
+        assert_eq!(signup.offset, -5);
+        assert_eq!(signup.nickname.as_deref(), Some("A"));
+        assert_eq!(signup.age, None);
+        assert_eq!(signup.password, "secret");
+    }
+
+    #[test]
+    fn derived_form_reports_errors() {
+        let mut state = FormState::parse(b"name=Ann&offset=-6&nickname=&age=x&password=");
+        assert!(Signup::from_state(&mut state).is_none());
+        assert_eq!(state.errors("offset"), ["Must be at least -5."]);
+        assert_eq!(state.errors("nickname"), ["This field is required."]);
+        assert_eq!(state.errors("age"), ["Enter a valid value."]);
+        assert!(state.errors("name").is_empty());
+    }
+
+    #[test]
+    fn derived_form_trims_opted_in_fields() {
+        let mut state =
+            FormState::parse(b"name=+Ann+&offset=+3&nickname=+A+&age=&password=+secret+");
+        let signup = Signup::from_state(&mut state).unwrap();
+        assert_eq!(signup.name, "Ann");
+        assert_eq!(signup.offset, 3);
+        assert_eq!(signup.nickname.as_deref(), Some(" A "));
+        assert_eq!(signup.password, " secret ");
+
+        let mut state = FormState::parse(b"name=Ann&offset=3&nickname=A&age=+4&password=");
+        assert!(Signup::from_state(&mut state).is_none());
+        assert_eq!(state.errors("age"), ["Enter a valid value."]);
+    }
+
+    fn ready<F: std::future::Future>(future: F) -> F::Output {
+        let mut context = std::task::Context::from_waker(std::task::Waker::noop());
+        match std::pin::pin!(future).poll(&mut context) {
+            std::task::Poll::Ready(output) => output,
+            std::task::Poll::Pending => panic!("future is pending"),
+        }
+    }
+
+    #[test]
+    fn from_request_limits_body() {
+        let request = |body: &'static str| {
+            Request::post("/signup")
+                .body(Full::new(Bytes::from(body)))
+                .unwrap()
+        };
+
+        let state = ready(FormState::from_request_limited(request("name=Ann"), 8)).unwrap();
+        assert_eq!(state.value("name"), Some("Ann"));
+
+        let error = ready(FormState::from_request_limited(request("name=Anne"), 8)).unwrap_err();
+        assert!(matches!(error, FormError::TooLarge));
+        assert_eq!(error.response().status(), StatusCode::PAYLOAD_TOO_LARGE);
+
+        let query = Request::get("/signup?name=Ann")
+            .body(Full::new(Bytes::new()))
+            .unwrap();
+        let state = ready(FormState::from_request(query)).unwrap();
+        assert_eq!(state.value("name"), Some("Ann"));
+    }
+
+    #[test]
+    fn derived_form_renders() {
+        let signup = Signup {
+            name: "Ann".to_string(),
+            offset: 2,
+            nickname: None,
+            age: Some(30),
+            password: "secret".to_string(),
+        };
+        let form = Node::from(signup.edit_form(FormMethod::Post, "signup"));
+
+        let input = |name: &str| {
+            form.select(&format!("[name={name}]"))[0]
+                .attributes()
+                .unwrap()
+                .clone()
+        };
+        assert_eq!(input("name").get("value"), Some("Ann"));
+        assert_eq!(input("name").get("required"), Some("required"));
+        assert_eq!(input("offset").get("min"), Some("-5"));
+        assert_eq!(input("offset").get("max"), Some("10"));
+        assert_eq!(input("nickname").get("required"), Some("required"));
+        assert_eq!(input("nickname").get("value"), None);
+        assert_eq!(input("age").get("value"), Some("30"));
+        assert_eq!(input("password").get("type"), Some("password"));
+        assert_eq!(input("password").get("value"), None);
+        assert_eq!(form.select("label[for=signup-offset]").len(), 1);
+    }
+}
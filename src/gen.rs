@@ -11,23 +11,6 @@ element_attribute!(Html , manifest , "manifest" , "Specifies the URI of a resour
 element_attribute!(Html , version , "version" , "Specifies the version of the HTML [Document Type Definition](https://developer.mozilla.org/en-US/docs/Glossary/DTD \"Document Type Definition: In HTML, the doctype is the required \"<!DOCTYPE html>\" preamble found at the top of all documents. Its sole purpose is to prevent a browser from switching into so-called “quirks mode” when rendering a document; that is, the \"<!DOCTYPE html>\" doctype ensures that the browser makes a best-effort attempt at following the relevant specifications, rather than using a different rendering mode that is incompatible with some specifications.\") that governs the current document. This attribute is not needed, because it is redundant with the version information in the document type declaration.") ;
 element_attribute!(Html , xmlns , "xmlns" , "Specifies the XML Namespace of the document. Default value is `\"http://www.w3.org/1999/xhtml\"`. This is required in documents parsed with XML parsers, and optional in text/html documents.") ;
 
-impl Html {
-    pub fn response(&self) -> hyper::Response<http_body_util::Full<hyper::body::Bytes>> {
-        hyper::Response::builder()
-            .header(hyper::header::CONTENT_TYPE, "text/html; charset=utf-8")
-            .body(http_body_util::Full::new(hyper::body::Bytes::from(
-                format!("<!DOCTYPE html>\n{self}"),
-            )))
-            .unwrap()
-    }
-
-    pub fn response_ok<E>(
-        &self,
-    ) -> Result<hyper::Response<http_body_util::Full<hyper::body::Bytes>>, E> {
-        Ok(self.response())
-    }
-}
-
 pub fn html(lang: impl Display) -> Html {
     Html::new_empty().attribute("lang", lang)
 }
@@ -697,28 +680,6 @@ element_attribute ! (Form , novalidate , "novalidate" , "This Boolean attribute
 element_attribute ! (Form , target , "target" , "A name or keyword indicating where to display the response that is received after submitting the form. In HTML 4, this is the name/keyword for a frame. In HTML5, it is a name/keyword for a _browsing context_ (for example, tab, window, or inline frame). The following keywords have special meanings:\n\n*   `_self`: Load the response into the same HTML 4 frame (or HTML5 browsing context) as the current one. This value is the default if the attribute is not specified.\n*   `_blank`: Load the response into a new unnamed HTML 4 window or HTML5 browsing context.\n*   `_parent`: Load the response into the HTML 4 frameset parent of the current frame, or HTML5 parent browsing context of the current one. If there is no parent, this option behaves the same way as `_self`.\n*   `_top`: HTML 4: Load the response into the full original window, and cancel all other frames. HTML5: Load the response into the top-level browsing context (i.e., the browsing context that is an ancestor of the current one, and has no parent). If there is no parent, this option behaves the same way as `_self`.\n*   _iframename_: The response is displayed in a named [`<iframe>`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/iframe \"The HTML Inline Frame element (<iframe>) represents a nested browsing context, embedding another HTML page into the current one.\").\n\nHTML5: This value can be overridden by a [`formtarget`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/button#attr-formtarget) attribute on a [`<button>`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/button \"The HTML <button> element represents a clickable button, which can be used in forms or anywhere in a document that needs simple, standard button functionality.\") or [`<input>`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input \"The HTML <input> element is used to create interactive controls for web-based forms in order to accept data from the user; a wide variety of types of input data and control widgets are available, depending on the device and user agent.\") element.") ;
 element_attribute ! (Form , accept , "accept" , "A comma-separated list of content types that the server accepts.\n\n**Usage note:** This attribute has been removed in HTML5 and should no longer be used. Instead, use the [`accept`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input#attr-accept) attribute of the specific [`<input>`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input \"The HTML <input> element is used to create interactive controls for web-based forms in order to accept data from the user; a wide variety of types of input data and control widgets are available, depending on the device and user agent.\") element.") ;
 element_attribute ! (Form , autocapitalize , "autocapitalize" , "This is a nonstandard attribute used by iOS Safari Mobile which controls whether and how the text value for textual form control descendants should be automatically capitalized as it is entered/edited by the user. If the `autocapitalize` attribute is specified on an individual form control descendant, it trumps the form-wide `autocapitalize` setting. The non-deprecated values are available in iOS 5 and later. The default value is `sentences`. Possible values are:\n\n*   `none`: Completely disables automatic capitalization\n*   `sentences`: Automatically capitalize the first letter of sentences.\n*   `words`: Automatically capitalize the first letter of words.\n*   `characters`: Automatically capitalize all characters.\n*   `on`: Deprecated since iOS 5.\n*   `off`: Deprecated since iOS 5.") ;
-pub fn form(method: FormMethod, action: impl Display) -> Form {
-    Form::new_empty()
-        .attribute("method", method)
-        .attribute("action", action)
-}
-
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub enum FormMethod {
-    Get,
-    Post,
-    Dialog,
-}
-
-impl Display for FormMethod {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::Get => write!(f, "get"),
-            Self::Post => write!(f, "post"),
-            Self::Dialog => write!(f, "dialog"),
-        }
-    }
-}
 
 element_struct ! (Label , label , "The label element represents a caption in a user interface. The caption can be associated with a specific form control, known as the label element's labeled control, either using the for attribute, or by putting the form control inside the label element itself.") ;
 element_attribute ! (Label , form , "form" , "The [`<form>`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/form \"The HTML <form> element represents a document section that contains interactive controls for submitting information to a web server.\") element with which the label is associated (its _form owner_). If specified, the value of the attribute is the `id` of a [`<form>`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/form \"The HTML <form> element represents a document section that contains interactive controls for submitting information to a web server.\") element in the same document. This lets you place label elements anywhere within a document, not just as descendants of their form elements.") ;
@@ -759,11 +720,6 @@ element_attribute!(Input, src, "src", "");
 element_attribute!(Input, step, "step", "");
 element_attribute!(Input, value, "value", "");
 element_attribute!(Input, width, "width", "");
-element_attribute!(Input, r#type, "type", "");
-// TODO: make nice API
-pub fn input() -> Input {
-    Input::new_empty()
-}
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum InputType {
@@ -791,6 +747,7 @@ pub enum InputType {
     Week,
 }
 
+#[allow(clippy::derivable_impls)]
 impl Default for InputType {
     fn default() -> Self {
         Self::Text
@@ -850,12 +807,6 @@ element_attribute ! (Button , r#type , "type" , "The type of the button. Possibl
 element_attribute ! (Button , value , "value" , "The initial value of the button. It defines the value associated with the button which is submitted with the form data. This value is passed to the server in params when the form is submitted.") ;
 element_attribute ! (Button , autocomplete , "autocomplete" , "The use of this attribute on a [`<button>`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/button \"The HTML <button> element represents a clickable button, which can be used in forms or anywhere in a document that needs simple, standard button functionality.\") is nonstandard and Firefox-specific. By default, unlike other browsers, [Firefox persists the dynamic disabled state](https://stackoverflow.com/questions/5985839/bug-with-firefox-disabled-attribute-of-input-not-resetting-when-refreshing) of a [`<button>`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/button \"The HTML <button> element represents a clickable button, which can be used in forms or anywhere in a document that needs simple, standard button functionality.\") across page loads. Setting the value of this attribute to `off` (i.e. `autocomplete=\"off\"`) disables this feature. See [bug\u{a0}654072](https://bugzilla.mozilla.org/show_bug.cgi?id=654072 \"if disabled state is changed with javascript, the normal state doesn't return after refreshing the page\").") ;
 
-impl Button {
-    pub fn onclick(self, event: impl Display) -> Self {
-        self.attribute("onclick", event)
-    }
-}
-
 pub fn button(id: impl Display) -> Button {
     Button::new_empty().id(id)
 }
@@ -883,19 +834,6 @@ element_boolean_attribute!(
     "A Boolean attribute indicating that an option with a non-empty string value must be selected."
 );
 element_attribute ! (Select , size , "size" , "If the control is presented as a scrolling list box (e.g. when `multiple` is specified), this attribute represents the number of rows in the list that should be visible at one time. Browsers are not required to present a select element as a scrolled list box. The default value is 0.\n\n**Note:** According to the HTML5 specification, the default value for size should be 1; however, in practice, this has been found to break some web sites, and no other browser currently does that, so Mozilla has opted to continue to return 0 for the time being with Firefox.") ;
-impl Select {
-    pub fn option(self, text: impl Display, value: impl Display) -> Self {
-        self.child(option(text, value))
-    }
-
-    pub fn options(self, options: impl IntoIterator<Item = (impl Display, impl Display)>) -> Self {
-        self.children(options.into_iter().map(|(text, value)| option(text, value)))
-    }
-}
-
-pub fn select(id: impl Display, name: impl Display) -> Select {
-    Select::new_empty().id(&id).name(&name)
-}
 
 element_struct ! (Datalist , datalist , "The datalist element represents a set of option elements that represent predefined options for other controls. In the rendering, the datalist element represents nothing and it, along with its children, should be hidden.") ;
 element_struct!(
@@ -907,11 +845,7 @@ element_attribute ! (Optgroup , disabled , "disabled" , "If this Boolean attribu
 element_attribute ! (Optgroup , label , "label" , "The name of the group of options, which the browser can use when labeling the options in the user interface. This attribute is mandatory if this element is used.") ;
 element_struct ! (Option , option , "The option element represents an option in a select element or as part of a list of suggestions in a datalist element.") ;
 element_boolean_attribute! (Option , disabled , set_disabled, "disabled" , "If this Boolean attribute is set, this option is not checkable. Often browsers grey out such control and it won't receive any browsing event, like mouse clicks or focus-related ones. If this attribute is not set, the element can still be disabled if one of its ancestors is a disabled [`<optgroup>`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/optgroup \"The HTML <optgroup> element creates a grouping of options within a <select> element.\") element.") ;
-element_boolean_attribute!(Option, checked, set_checked, "checked", "");
 element_attribute ! (Option , label , "label" , "This attribute is text for the label indicating the meaning of the option. If the `label` attribute isn't defined, its value is that of the element text content.") ;
-pub fn option(text: impl Display, value: impl Display) -> Option {
-    Option::new_empty().text(text).attribute("value", value)
-}
 element_struct ! (Textarea , textarea , "The textarea element represents a multiline plain text edit control for the element's raw value. The contents of the control represent the control's default value.") ;
 element_attribute ! (Textarea , autocomplete , "autocomplete" , "This attribute indicates whether the value of the control can be automatically completed by the browser. Possible values are:\n\n*   `off`: The user must explicitly enter a value into this field for every use, or the document provides its own auto-completion method; the browser does not automatically complete the entry.\n*   `on`: The browser can automatically complete the value based on values that the user has entered during previous uses.\n\nIf the `autocomplete` attribute is not specified on a `<textarea>` element, then the browser uses the `autocomplete` attribute value of the `<textarea>` element's form owner. The form owner is either the [`<form>`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/form \"The HTML <form> element represents a document section that contains interactive controls for submitting information to a web server.\") element that this `<textarea>` element is a descendant of or the form element whose `id` is specified by the `form` attribute of the input element. For more information, see the [`autocomplete`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/form#attr-autocomplete) attribute in [`<form>`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/form \"The HTML <form> element represents a document section that contains interactive controls for submitting information to a web server.\").") ;
 element_attribute ! (Textarea , autofocus , "autofocus" , "This Boolean attribute lets you specify that a form control should have input focus when the page loads. Only one form-associated element in a document can have this attribute specified.") ;
@@ -1000,6 +934,7 @@ element_attribute ! (Script , referrerpolicy , "referrerpolicy" , "Indicates whi
 element_attribute ! (Script , text , "text" , "Like the `textContent` attribute, this attribute sets the text content of the element. Unlike the `textContent` attribute, however, this attribute is evaluated as executable code after the node is inserted into the DOM.") ;
 element_struct ! (Noscript , noscript , "The noscript element represents nothing if scripting is enabled, and represents its children if scripting is disabled. It is used to present different markup to user agents that support scripting and those that don't support scripting, by affecting how the document is parsed.") ;
 element_struct ! (Template , template , "The template element is used to declare fragments of HTML that can be cloned and inserted in the document by script.") ;
+
 element_struct ! (Canvas , canvas , "The canvas element provides scripts with a resolution-dependent bitmap canvas, which can be used for rendering graphs, game graphics, art, or other visual images on the fly.") ;
 element_attribute!(
     Canvas,
@@ -1021,6 +956,7 @@ element_attribute!(
     "name",
     "The slot's name.\nA **named slot** is a `<slot>` element with a `name` attribute."
 );
+
 element_struct!(
     Data,
     data,
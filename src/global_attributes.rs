@@ -0,0 +1,671 @@
+use crate::macros::keywords;
+
+keywords!(Event {
+    Abort => "abort",
+    Animationend => "animationend",
+    Animationstart => "animationstart",
+    Auxclick => "auxclick",
+    Beforeinput => "beforeinput",
+    Beforetoggle => "beforetoggle",
+    Blur => "blur",
+    Canplay => "canplay",
+    Canplaythrough => "canplaythrough",
+    Change => "change",
+    Click => "click",
+    Contextmenu => "contextmenu",
+    Copy => "copy",
+    Cut => "cut",
+    Dblclick => "dblclick",
+    Drag => "drag",
+    Dragend => "dragend",
+    Dragenter => "dragenter",
+    Dragleave => "dragleave",
+    Dragover => "dragover",
+    Dragstart => "dragstart",
+    Drop => "drop",
+    Durationchange => "durationchange",
+    Emptied => "emptied",
+    Ended => "ended",
+    Error => "error",
+    Focus => "focus",
+    Formchange => "formchange",
+    Forminput => "forminput",
+    Input => "input",
+    Invalid => "invalid",
+    Keydown => "keydown",
+    Keypress => "keypress",
+    Keyup => "keyup",
+    Load => "load",
+    Loadeddata => "loadeddata",
+    Loadedmetadata => "loadedmetadata",
+    Loadstart => "loadstart",
+    Mousedown => "mousedown",
+    Mouseenter => "mouseenter",
+    Mouseleave => "mouseleave",
+    Mousemove => "mousemove",
+    Mouseout => "mouseout",
+    Mouseover => "mouseover",
+    Mouseup => "mouseup",
+    Mousewheel => "mousewheel",
+    Paste => "paste",
+    Pause => "pause",
+    Play => "play",
+    Playing => "playing",
+    Pointercancel => "pointercancel",
+    Pointerdown => "pointerdown",
+    Pointerenter => "pointerenter",
+    Pointerleave => "pointerleave",
+    Pointerlockchange => "pointerlockchange",
+    Pointerlockerror => "pointerlockerror",
+    Pointermove => "pointermove",
+    Pointerout => "pointerout",
+    Pointerover => "pointerover",
+    Pointerup => "pointerup",
+    Progress => "progress",
+    Ratechange => "ratechange",
+    Readystatechange => "readystatechange",
+    Reset => "reset",
+    Resize => "resize",
+    Scroll => "scroll",
+    Seeked => "seeked",
+    Seeking => "seeking",
+    Select => "select",
+    Show => "show",
+    Stalled => "stalled",
+    Submit => "submit",
+    Suspend => "suspend",
+    Timeupdate => "timeupdate",
+    Toggle => "toggle",
+    Touchcancel => "touchcancel",
+    Touchend => "touchend",
+    Touchmove => "touchmove",
+    Touchstart => "touchstart",
+    Transitionend => "transitionend",
+    Volumechange => "volumechange",
+    Waiting => "waiting",
+    Wheel => "wheel",
+});
+
+/// Expands to a typed method on the `Element` trait for every global attribute.
+macro_rules! global_attributes {
+    () => {
+        #[doc = "Provides a hint for generating a keyboard shortcut for the current element. This attribute consists of a space-separated list of characters. The browser should use the first one that exists on the computer keyboard layout."]
+        fn accesskey(self, value: impl std::fmt::Display) -> Self {
+            self.attribute("accesskey", value)
+        }
+
+        #[doc = "Controls whether and how text input is automatically capitalized as it is entered/edited by the user."]
+        fn autocapitalize(self, value: crate::Autocapitalize) -> Self {
+            self.attribute("autocapitalize", value)
+        }
+
+        #[doc = "Indicates that an element is to be focused on page load, or as soon as the dialog it is part of is displayed."]
+        fn autofocus(self) -> Self {
+            self.attribute("autofocus", "autofocus")
+        }
+
+        fn set_autofocus(self, value: bool) -> Self {
+            if value {
+                self.autofocus()
+            } else {
+                self.remove_attribute("autofocus")
+            }
+        }
+
+        #[doc = "An enumerated attribute indicating if the element should be editable by the user."]
+        fn contenteditable(self, value: crate::ContentEditable) -> Self {
+            self.attribute("contenteditable", value)
+        }
+
+        #[doc = "An enumerated attribute indicating the directionality of the element's text."]
+        fn dir(self, value: crate::Dir) -> Self {
+            self.attribute("dir", value)
+        }
+
+        #[doc = "An enumerated attribute indicating whether the element can be dragged, using the Drag and Drop API."]
+        fn draggable(self, value: bool) -> Self {
+            self.attribute("draggable", value)
+        }
+
+        #[doc = "Hints what action label (or icon) to present for the enter key on virtual keyboards."]
+        fn enterkeyhint(self, value: crate::EnterKeyHint) -> Self {
+            self.attribute("enterkeyhint", value)
+        }
+
+        #[doc = "Used to transitively export shadow parts from a nested shadow tree into a containing light tree."]
+        fn exportparts(self, value: impl std::fmt::Display) -> Self {
+            self.attribute("exportparts", value)
+        }
+
+        #[doc = "A Boolean attribute indicates that the element is not yet, or is no longer, _relevant_. Browsers won't render elements with the `hidden` attribute set."]
+        fn hidden(self) -> Self {
+            self.attribute("hidden", "hidden")
+        }
+
+        fn set_hidden(self, value: bool) -> Self {
+            if value {
+                self.hidden()
+            } else {
+                self.remove_attribute("hidden")
+            }
+        }
+
+        #[doc = "Makes the browser disregard user input events for the element, including focus events and events from assistive technologies."]
+        fn inert(self) -> Self {
+            self.attribute("inert", "inert")
+        }
+
+        fn set_inert(self, value: bool) -> Self {
+            if value {
+                self.inert()
+            } else {
+                self.remove_attribute("inert")
+            }
+        }
+
+        #[doc = "Provides a hint to browsers as to the type of virtual keyboard configuration to use when editing this element or its contents."]
+        fn inputmode(self, value: crate::InputMode) -> Self {
+            self.attribute("inputmode", value)
+        }
+
+        #[doc = "Allows you to specify that a standard HTML element should behave like a registered custom built-in element."]
+        fn is(self, value: impl std::fmt::Display) -> Self {
+            self.attribute("is", value)
+        }
+
+        #[doc = "The unique, global identifier of an item."]
+        fn itemid(self, value: impl std::fmt::Display) -> Self {
+            self.attribute("itemid", value)
+        }
+
+        #[doc = "Used to add properties to an item. Every HTML element may have an `itemprop` attribute specified, where an `itemprop` consists of a name and value pair."]
+        fn itemprop(self, value: impl std::fmt::Display) -> Self {
+            self.attribute("itemprop", value)
+        }
+
+        #[doc = "Properties that are not descendants of an element with the `itemscope` attribute can be associated with the item using an `itemref`."]
+        fn itemref(self, value: impl std::fmt::Display) -> Self {
+            self.attribute("itemref", value)
+        }
+
+        #[doc = "`itemscope` (usually) works along with `itemtype` to specify that the HTML contained in a block is about a particular item."]
+        fn itemscope(self) -> Self {
+            self.attribute("itemscope", "itemscope")
+        }
+
+        fn set_itemscope(self, value: bool) -> Self {
+            if value {
+                self.itemscope()
+            } else {
+                self.remove_attribute("itemscope")
+            }
+        }
+
+        #[doc = "Specifies the URL of the vocabulary that will be used to define `itemprop`s (item properties) in the data structure."]
+        fn itemtype(self, value: impl std::fmt::Display) -> Self {
+            self.attribute("itemtype", value)
+        }
+
+        #[doc = "Helps define the language of an element: the language that non-editable elements are in, or the language that editable elements should be written in by the user."]
+        fn lang(self, value: impl std::fmt::Display) -> Self {
+            self.attribute("lang", value)
+        }
+
+        #[doc = "A cryptographic nonce used by Content Security Policy to determine whether a given fetch will be allowed to proceed."]
+        fn nonce(self, value: impl std::fmt::Display) -> Self {
+            self.attribute("nonce", value)
+        }
+
+        #[doc = "A space-separated list of the part names of the element. Part names allows CSS to select and style specific elements in a shadow tree via the `::part` pseudo-element."]
+        fn part(self, value: impl std::fmt::Display) -> Self {
+            self.attribute("part", value)
+        }
+
+        #[doc = "Designates an element as a popover element."]
+        fn popover(self, value: crate::Popover) -> Self {
+            self.attribute("popover", value)
+        }
+
+        #[doc = "Assigns a slot in a shadow DOM shadow tree to an element: An element with a `slot` attribute is assigned to the slot created by the `slot` element whose `name` attribute's value matches that `slot` attribute's value."]
+        fn slot(self, value: impl std::fmt::Display) -> Self {
+            self.attribute("slot", value)
+        }
+
+        #[doc = "An enumerated attribute defines whether the element may be checked for spelling errors."]
+        fn spellcheck(self, value: bool) -> Self {
+            self.attribute("spellcheck", value)
+        }
+
+        #[doc = "An integer attribute indicating if the element can take input focus (is _focusable_), if it should participate to sequential keyboard navigation, and if so, at what position."]
+        fn tabindex(self, value: i32) -> Self {
+            self.attribute("tabindex", value)
+        }
+
+        #[doc = "Contains a text representing advisory information related to the element it belongs to. Such information can typically, but not necessarily, be presented to the user as a tooltip."]
+        fn title(self, value: impl std::fmt::Display) -> Self {
+            self.attribute("title", value)
+        }
+
+        #[doc = "An enumerated attribute that is used to specify whether an element's attribute values and the values of its `Text` node children are to be translated when the page is localized, or whether to leave them unchanged."]
+        fn translate(self, value: bool) -> Self {
+            self.attribute("translate", if value { "yes" } else { "no" })
+        }
+
+        #[doc = "The loading of a resource has been aborted."]
+        fn onabort(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onabort", script)
+        }
+
+        #[doc = "Sets the script run for the `animationend` event."]
+        fn onanimationend(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onanimationend", script)
+        }
+
+        #[doc = "Sets the script run for the `animationstart` event."]
+        fn onanimationstart(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onanimationstart", script)
+        }
+
+        #[doc = "Sets the script run for the `auxclick` event."]
+        fn onauxclick(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onauxclick", script)
+        }
+
+        #[doc = "Sets the script run for the `beforeinput` event."]
+        fn onbeforeinput(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onbeforeinput", script)
+        }
+
+        #[doc = "Sets the script run for the `beforetoggle` event."]
+        fn onbeforetoggle(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onbeforetoggle", script)
+        }
+
+        #[doc = "An element has lost focus (does not bubble)."]
+        fn onblur(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onblur", script)
+        }
+
+        #[doc = "The user agent can play the media, but estimates that not enough data has been loaded to play the media up to its end without having to stop for further buffering of content."]
+        fn oncanplay(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("oncanplay", script)
+        }
+
+        #[doc = "The user agent can play the media up to its end without having to stop for further buffering of content."]
+        fn oncanplaythrough(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("oncanplaythrough", script)
+        }
+
+        #[doc = "The change event is fired for `<input>`, `<select>`, and `<textarea>` elements when a change to the element's value is committed by the user."]
+        fn onchange(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onchange", script)
+        }
+
+        #[doc = "A pointing device button has been pressed and released on an element."]
+        fn onclick(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onclick", script)
+        }
+
+        #[doc = "The right button of the mouse is clicked (before the context menu is displayed)."]
+        fn oncontextmenu(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("oncontextmenu", script)
+        }
+
+        #[doc = "Sets the script run for the `copy` event."]
+        fn oncopy(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("oncopy", script)
+        }
+
+        #[doc = "Sets the script run for the `cut` event."]
+        fn oncut(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("oncut", script)
+        }
+
+        #[doc = "A pointing device button is clicked twice on an element."]
+        fn ondblclick(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("ondblclick", script)
+        }
+
+        #[doc = "An element or text selection is being dragged (every 350ms)."]
+        fn ondrag(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("ondrag", script)
+        }
+
+        #[doc = "A drag operation is being ended (by releasing a mouse button or hitting the escape key)."]
+        fn ondragend(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("ondragend", script)
+        }
+
+        #[doc = "A dragged element or text selection enters a valid drop target."]
+        fn ondragenter(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("ondragenter", script)
+        }
+
+        #[doc = "A dragged element or text selection leaves a valid drop target."]
+        fn ondragleave(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("ondragleave", script)
+        }
+
+        #[doc = "An element or text selection is being dragged over a valid drop target (every 350ms)."]
+        fn ondragover(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("ondragover", script)
+        }
+
+        #[doc = "The user starts dragging an element or text selection."]
+        fn ondragstart(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("ondragstart", script)
+        }
+
+        #[doc = "An element is dropped on a valid drop target."]
+        fn ondrop(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("ondrop", script)
+        }
+
+        #[doc = "The duration attribute has been updated."]
+        fn ondurationchange(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("ondurationchange", script)
+        }
+
+        #[doc = "The media has become empty; for example, this event is sent if the media has already been loaded (or partially loaded), and the load() method is called to reload it."]
+        fn onemptied(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onemptied", script)
+        }
+
+        #[doc = "Playback has stopped because the end of the media was reached."]
+        fn onended(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onended", script)
+        }
+
+        #[doc = "A resource failed to load."]
+        fn onerror(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onerror", script)
+        }
+
+        #[doc = "An element has received focus (does not bubble)."]
+        fn onfocus(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onfocus", script)
+        }
+
+        #[doc = "Sets the script run for the `formchange` event."]
+        fn onformchange(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onformchange", script)
+        }
+
+        #[doc = "Sets the script run for the `forminput` event."]
+        fn onforminput(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onforminput", script)
+        }
+
+        #[doc = "The value of an element changes or the content of an element with the attribute contenteditable is modified."]
+        fn oninput(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("oninput", script)
+        }
+
+        #[doc = "A submittable element has been checked and doesn't satisfy its constraints."]
+        fn oninvalid(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("oninvalid", script)
+        }
+
+        #[doc = "A key is pressed down."]
+        fn onkeydown(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onkeydown", script)
+        }
+
+        #[doc = "A key is pressed down and that key normally produces a character value (use input instead)."]
+        fn onkeypress(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onkeypress", script)
+        }
+
+        #[doc = "A key is released."]
+        fn onkeyup(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onkeyup", script)
+        }
+
+        #[doc = "A resource and its dependent resources have finished loading."]
+        fn onload(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onload", script)
+        }
+
+        #[doc = "The first frame of the media has finished loading."]
+        fn onloadeddata(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onloadeddata", script)
+        }
+
+        #[doc = "The metadata has been loaded."]
+        fn onloadedmetadata(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onloadedmetadata", script)
+        }
+
+        #[doc = "Progress has begun."]
+        fn onloadstart(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onloadstart", script)
+        }
+
+        #[doc = "A pointing device button (usually a mouse) is pressed on an element."]
+        fn onmousedown(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onmousedown", script)
+        }
+
+        #[doc = "A pointing device is moved onto the element that has the listener attached."]
+        fn onmouseenter(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onmouseenter", script)
+        }
+
+        #[doc = "A pointing device is moved off the element that has the listener attached."]
+        fn onmouseleave(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onmouseleave", script)
+        }
+
+        #[doc = "A pointing device is moved over an element."]
+        fn onmousemove(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onmousemove", script)
+        }
+
+        #[doc = "A pointing device is moved off the element that has the listener attached or off one of its children."]
+        fn onmouseout(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onmouseout", script)
+        }
+
+        #[doc = "A pointing device is moved onto the element that has the listener attached or onto one of its children."]
+        fn onmouseover(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onmouseover", script)
+        }
+
+        #[doc = "A pointing device button is released over an element."]
+        fn onmouseup(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onmouseup", script)
+        }
+
+        #[doc = "Sets the script run for the `mousewheel` event."]
+        fn onmousewheel(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onmousewheel", script)
+        }
+
+        #[doc = "Sets the script run for the `paste` event."]
+        fn onpaste(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onpaste", script)
+        }
+
+        #[doc = "Playback has been paused."]
+        fn onpause(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onpause", script)
+        }
+
+        #[doc = "Playback has begun."]
+        fn onplay(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onplay", script)
+        }
+
+        #[doc = "Playback is ready to start after having been paused or delayed due to lack of data."]
+        fn onplaying(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onplaying", script)
+        }
+
+        #[doc = "The pointer is unlikely to produce any more events."]
+        fn onpointercancel(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onpointercancel", script)
+        }
+
+        #[doc = "The pointer enters the active buttons state."]
+        fn onpointerdown(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onpointerdown", script)
+        }
+
+        #[doc = "Pointing device is moved inside the hit-testing boundary."]
+        fn onpointerenter(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onpointerenter", script)
+        }
+
+        #[doc = "Pointing device is moved out of the hit-testing boundary."]
+        fn onpointerleave(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onpointerleave", script)
+        }
+
+        #[doc = "The pointer was locked or released."]
+        fn onpointerlockchange(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onpointerlockchange", script)
+        }
+
+        #[doc = "It was impossible to lock the pointer for technical reasons or because the permission was denied."]
+        fn onpointerlockerror(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onpointerlockerror", script)
+        }
+
+        #[doc = "The pointer changed coordinates."]
+        fn onpointermove(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onpointermove", script)
+        }
+
+        #[doc = "The pointing device moved out of hit-testing boundary or leaves detectable hover range."]
+        fn onpointerout(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onpointerout", script)
+        }
+
+        #[doc = "The pointing device is moved into the hit-testing boundary."]
+        fn onpointerover(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onpointerover", script)
+        }
+
+        #[doc = "The pointer leaves the active buttons state."]
+        fn onpointerup(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onpointerup", script)
+        }
+
+        #[doc = "In progress."]
+        fn onprogress(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onprogress", script)
+        }
+
+        #[doc = "The playback rate has changed."]
+        fn onratechange(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onratechange", script)
+        }
+
+        #[doc = "The readyState attribute of a document has changed."]
+        fn onreadystatechange(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onreadystatechange", script)
+        }
+
+        #[doc = "A form is reset."]
+        fn onreset(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onreset", script)
+        }
+
+        #[doc = "The document view has been resized."]
+        fn onresize(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onresize", script)
+        }
+
+        #[doc = "The document view or an element has been scrolled."]
+        fn onscroll(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onscroll", script)
+        }
+
+        #[doc = "A seek operation completed."]
+        fn onseeked(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onseeked", script)
+        }
+
+        #[doc = "A seek operation began."]
+        fn onseeking(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onseeking", script)
+        }
+
+        #[doc = "Some text is being selected."]
+        fn onselect(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onselect", script)
+        }
+
+        #[doc = "A contextmenu event was fired on/bubbled to an element that has a contextmenu attribute"]
+        fn onshow(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onshow", script)
+        }
+
+        #[doc = "The user agent is trying to fetch media data, but data is unexpectedly not forthcoming."]
+        fn onstalled(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onstalled", script)
+        }
+
+        #[doc = "A form is submitted."]
+        fn onsubmit(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onsubmit", script)
+        }
+
+        #[doc = "Media data loading has been suspended."]
+        fn onsuspend(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onsuspend", script)
+        }
+
+        #[doc = "The time indicated by the currentTime attribute has been updated."]
+        fn ontimeupdate(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("ontimeupdate", script)
+        }
+
+        #[doc = "Sets the script run for the `toggle` event."]
+        fn ontoggle(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("ontoggle", script)
+        }
+
+        #[doc = "Sets the script run for the `touchcancel` event."]
+        fn ontouchcancel(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("ontouchcancel", script)
+        }
+
+        #[doc = "Sets the script run for the `touchend` event."]
+        fn ontouchend(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("ontouchend", script)
+        }
+
+        #[doc = "Sets the script run for the `touchmove` event."]
+        fn ontouchmove(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("ontouchmove", script)
+        }
+
+        #[doc = "Sets the script run for the `touchstart` event."]
+        fn ontouchstart(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("ontouchstart", script)
+        }
+
+        #[doc = "Sets the script run for the `transitionend` event."]
+        fn ontransitionend(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("ontransitionend", script)
+        }
+
+        #[doc = "The volume has changed."]
+        fn onvolumechange(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onvolumechange", script)
+        }
+
+        #[doc = "Playback has stopped because of a temporary lack of data."]
+        fn onwaiting(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onwaiting", script)
+        }
+
+        #[doc = "Sets the script run for the `wheel` event."]
+        fn onwheel(self, script: impl std::fmt::Display) -> Self {
+            self.attribute("onwheel", script)
+        }
+    };
+}
+
+pub(crate) use global_attributes;
@@ -0,0 +1,22 @@
+use std::fmt::Display;
+
+use crate::node::prelude::*;
+use crate::prelude::{Head, Link, Script};
+
+pub fn link(rel: impl Display, href: impl Display) -> Link {
+    Link::new_empty().rel(rel).href(href)
+}
+
+pub fn script(src: impl Display) -> Script {
+    Script::new_empty().src(src)
+}
+
+impl Head {
+    pub fn stylesheet(self, href: impl Display) -> Self {
+        self.child(link("stylesheet", href))
+    }
+
+    pub fn script(self, src: impl Display) -> Self {
+        self.child(script(src).defer("defer"))
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{macros::keywords, Event};
+
+/// An event that an `hx-on` handler can listen for, see [`Element::hx_on`](crate::Element::hx_on).
+pub trait HxEvent {
+    fn hx_on_attribute(&self) -> String;
+}
+
+impl HxEvent for Event {
+    fn hx_on_attribute(&self) -> String {
+        format!("hx-on:{self}")
+    }
+}
+
+/// An event that isn't built into the browser or htmx, e.g. one dispatched by application code.
+///
+/// `CustomEvent::new("my-event")` sets `hx-on:my-event`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CustomEvent(String);
+
+impl CustomEvent {
+    pub fn new(name: impl std::fmt::Display) -> Self {
+        Self(name.to_string())
+    }
+
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+}
+
+impl HxEvent for CustomEvent {
+    fn hx_on_attribute(&self) -> String {
+        format!("hx-on:{}", self.0)
+    }
+}
+
+impl HxEvent for HtmxEvent {
+    fn hx_on_attribute(&self) -> String {
+        format!("hx-on::{self}")
+    }
+}
+
+keywords!(
+    /// An event triggered by htmx itself, without the `htmx:` prefix.
+    HtmxEvent {
+        Abort => "abort",
+        AfterOnLoad => "after-on-load",
+        AfterProcessNode => "after-process-node",
+        AfterRequest => "after-request",
+        AfterSettle => "after-settle",
+        AfterSwap => "after-swap",
+        BeforeCleanupElement => "before-cleanup-element",
+        BeforeHistorySave => "before-history-save",
+        BeforeOnLoad => "before-on-load",
+        BeforeProcessNode => "before-process-node",
+        BeforeRequest => "before-request",
+        BeforeSend => "before-send",
+        BeforeSwap => "before-swap",
+        ConfigRequest => "config-request",
+        Confirm => "confirm",
+        HistoryCacheError => "history-cache-error",
+        HistoryCacheMiss => "history-cache-miss",
+        HistoryCacheMissError => "history-cache-miss-error",
+        HistoryCacheMissLoad => "history-cache-miss-load",
+        HistoryRestore => "history-restore",
+        Load => "load",
+        NoSseSourceError => "no-sse-source-error",
+        OnLoadError => "on-load-error",
+        OobAfterSwap => "oob-after-swap",
+        OobBeforeSwap => "oob-before-swap",
+        OobErrorNoTarget => "oob-error-no-target",
+        Prompt => "prompt",
+        PushedIntoHistory => "pushed-into-history",
+        ResponseError => "response-error",
+        SendError => "send-error",
+        SseError => "sse-error",
+        SseOpen => "sse-open",
+        SwapError => "swap-error",
+        TargetError => "target-error",
+        Timeout => "timeout",
+        ValidationValidate => "validation:validate",
+        ValidationFailed => "validation:failed",
+        ValidationHalted => "validation:halted",
+        XhrAbort => "xhr:abort",
+        XhrLoadEnd => "xhr:loadend",
+        XhrLoadStart => "xhr:loadstart",
+        XhrProgress => "xhr:progress",
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{button, div, Element};
+
+    #[test]
+    fn hx_on_events() {
+        let html = div()
+            .hx_on(Event::Click, "a()")
+            .hx_on(HtmxEvent::AfterSwap, "b()")
+            .hx_on(CustomEvent::new("my-event"), "c()")
+            .hx_on(CustomEvent::new(String::from("other-event")), "d()")
+            .to_string();
+        assert!(html.contains(r#"hx-on:click="a()""#));
+        assert!(html.contains(r#"hx-on::after-swap="b()""#));
+        assert!(html.contains(r#"hx-on:my-event="c()""#));
+        assert!(html.contains(r#"hx-on:other-event="d()""#));
+    }
+
+    #[test]
+    fn button_onclick() {
+        let html = button("save").onclick("save()").to_string();
+        assert!(html.contains(r#"onclick="save()""#));
+    }
+}
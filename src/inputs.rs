@@ -0,0 +1,333 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::node::prelude::*;
+use crate::prelude::{input, label, InputType, OptionValue};
+use crate::{element_attribute, element_boolean_attribute, void_element_struct};
+
+/// Adds the attributes shared by every input type.
+macro_rules! input_attributes {
+    ($name:ident) => {
+        element_boolean_attribute!($name, disabled, set_disabled, "disabled", "");
+        element_attribute!($name, form, "form", "");
+        element_attribute!($name, name, "name", "");
+    };
+}
+
+/// Adds the attributes of text-like input types.
+macro_rules! text_attributes {
+    ($name:ident) => {
+        input_attributes!($name);
+        element_attribute!($name, autocomplete, "autocomplete", "");
+        element_attribute!($name, list, "list", "");
+        element_attribute!($name, maxlength, "maxlength", "");
+        element_attribute!($name, minlength, "minlength", "");
+        element_attribute!($name, pattern, "pattern", "");
+        element_attribute!($name, placeholder, "placeholder", "");
+        element_boolean_attribute!($name, readonly, set_readonly, "readonly", "");
+        element_boolean_attribute!($name, required, set_required, "required", "");
+        element_attribute!($name, size, "size", "");
+        element_attribute!($name, value, "value", "");
+    };
+}
+
+void_element_struct!(TextInput, input, "An `input` of type `text`.");
+text_attributes!(TextInput);
+element_attribute!(TextInput, dirname, "dirname", "");
+
+pub fn text_input(name: impl Display) -> TextInput {
+    TextInput::new_empty()
+        .attribute("type", InputType::Text)
+        .name(name)
+}
+
+void_element_struct!(EmailInput, input, "An `input` of type `email`.");
+text_attributes!(EmailInput);
+element_boolean_attribute!(EmailInput, multiple, set_multiple, "multiple", "");
+
+pub fn email_input(name: impl Display) -> EmailInput {
+    EmailInput::new_empty()
+        .attribute("type", InputType::Email)
+        .name(name)
+}
+
+void_element_struct!(NumberInput, input, "An `input` of type `number`.");
+input_attributes!(NumberInput);
+element_attribute!(NumberInput, autocomplete, "autocomplete", "");
+element_attribute!(NumberInput, list, "list", "");
+element_attribute!(NumberInput, max, "max", "");
+element_attribute!(NumberInput, min, "min", "");
+element_attribute!(NumberInput, placeholder, "placeholder", "");
+element_boolean_attribute!(NumberInput, readonly, set_readonly, "readonly", "");
+element_boolean_attribute!(NumberInput, required, set_required, "required", "");
+element_attribute!(NumberInput, step, "step", "");
+element_attribute!(NumberInput, value, "value", "");
+
+/// `step` is the granularity of values, e.g. `0.01`, or `"any"` to allow any value.
+pub fn number_input<T: Display>(
+    name: impl Display,
+    min: T,
+    max: T,
+    step: impl Display,
+) -> NumberInput {
+    NumberInput::new_empty()
+        .attribute("type", InputType::Number)
+        .name(name)
+        .min(min)
+        .max(max)
+        .step(step)
+}
+
+void_element_struct!(Checkbox, input, "An `input` of type `checkbox`.");
+input_attributes!(Checkbox);
+element_boolean_attribute!(Checkbox, checked, set_checked, "checked", "");
+element_boolean_attribute!(Checkbox, required, set_required, "required", "");
+element_attribute!(
+    Checkbox,
+    value,
+    "value",
+    "The value submitted when checked, `on` by default."
+);
+
+pub fn checkbox(name: impl Display, checked: bool) -> Checkbox {
+    Checkbox::new_empty()
+        .attribute("type", InputType::Checkbox)
+        .name(name)
+        .set_checked(checked)
+}
+
+/// Radio buttons sharing a name, each in a label, see [`radio_group`].
+#[derive(Debug, Clone)]
+pub struct RadioGroup {
+    name: String,
+    /// The text and value of each radio button.
+    options: Vec<(String, String)>,
+    checked: Option<String>,
+    required: bool,
+    disabled: bool,
+}
+
+/// `options` are `(text, value)` pairs or other [`OptionValue`]s, like for
+/// [`Select::options`](crate::prelude::Select::options).
+pub fn radio_group(
+    name: impl Display,
+    options: impl IntoIterator<Item = impl OptionValue>,
+) -> RadioGroup {
+    RadioGroup {
+        name: name.to_string(),
+        options: options
+            .into_iter()
+            .map(|option| (option.text(), option.value()))
+            .collect(),
+        checked: None,
+        required: false,
+        disabled: false,
+    }
+}
+
+impl RadioGroup {
+    /// Checks the radio button with this value.
+    pub fn checked(mut self, value: impl Display) -> Self {
+        self.checked = Some(value.to_string());
+        self
+    }
+
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    pub fn disabled(mut self) -> Self {
+        self.disabled = true;
+        self
+    }
+}
+
+impl From<RadioGroup> for Node {
+    fn from(value: RadioGroup) -> Self {
+        // Values can contain anything, so the ids use the option's position instead.
+        let radios = value.options.iter().enumerate();
+        Node::fragment(radios.map(|(index, (text, option))| {
+            let id = format!("{}-{index}", value.name);
+            let radio = input(InputType::Radio, &value.name)
+                .id(&id)
+                .value(option)
+                .set_checked(value.checked.as_ref() == Some(option))
+                .set_required(value.required)
+                .set_disabled(value.disabled);
+            label(id).child(radio).text(text)
+        }))
+    }
+}
+
+/// A calendar date, used for the values of [`DateInput`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// # Panics
+    /// If the date doesn't exist or the year is after 9999.
+    pub fn new(year: u16, month: u8, day: u8) -> Self {
+        Self::checked(year, month, day).unwrap_or_else(|| {
+            panic!("invalid date: {year:04}-{month:02}-{day:02}");
+        })
+    }
+
+    fn checked(year: u16, month: u8, day: u8) -> Option<Self> {
+        let leap =
+            year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400));
+        let days = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if leap => 29,
+            2 => 28,
+            _ => return None,
+        };
+        (year <= 9999 && (1..=days).contains(&day)).then_some(Self { year, month, day })
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+}
+
+/// Formats as `YYYY-MM-DD`, the format of date inputs.
+impl Display for Date {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Parses `YYYY-MM-DD`, the format date inputs submit.
+impl FromStr for Date {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || format!("invalid date: {s}");
+        let mut parts = s.split('-');
+        let (Some(year), Some(month), Some(day), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(error());
+        };
+        if year.len() != 4 || month.len() != 2 || day.len() != 2 {
+            return Err(error());
+        }
+        let year = year.parse().map_err(|_| error())?;
+        let month = month.parse().map_err(|_| error())?;
+        let day = day.parse().map_err(|_| error())?;
+        Self::checked(year, month, day).ok_or_else(error)
+    }
+}
+
+void_element_struct!(DateInput, input, "An `input` of type `date`.");
+input_attributes!(DateInput);
+element_attribute!(DateInput, autocomplete, "autocomplete", "");
+element_attribute!(DateInput, list, "list", "");
+element_boolean_attribute!(DateInput, readonly, set_readonly, "readonly", "");
+element_boolean_attribute!(DateInput, required, set_required, "required", "");
+element_attribute!(
+    DateInput,
+    step,
+    "step",
+    "The granularity of values in days."
+);
+
+impl DateInput {
+    pub fn value(self, value: Date) -> Self {
+        self.attribute("value", value)
+    }
+
+    pub fn min(self, value: Date) -> Self {
+        self.attribute("min", value)
+    }
+
+    pub fn max(self, value: Date) -> Self {
+        self.attribute("max", value)
+    }
+}
+
+pub fn date_input(name: impl Display) -> DateInput {
+    DateInput::new_empty()
+        .attribute("type", InputType::Date)
+        .name(name)
+}
+
+void_element_struct!(FileInput, input, "An `input` of type `file`.");
+input_attributes!(FileInput);
+element_attribute!(FileInput, accept, "accept", "");
+element_attribute!(
+    FileInput,
+    capture,
+    "capture",
+    "`user` or `environment`, the camera to capture with."
+);
+element_boolean_attribute!(FileInput, multiple, set_multiple, "multiple", "");
+element_boolean_attribute!(FileInput, required, set_required, "required", "");
+
+/// `accept` lists the accepted file types, e.g. `image/*` or `.pdf,.docx`.
+pub fn file_input(name: impl Display, accept: impl Display) -> FileInput {
+    FileInput::new_empty()
+        .attribute("type", InputType::File)
+        .name(name)
+        .accept(accept)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn radio_group_checks_value() {
+        let group = Node::from(radio_group("size", [("Small", "s"), ("Large", "l")]).checked("l"));
+        let checked = group.select("input[checked]");
+        assert_eq!(checked.len(), 1);
+        assert_eq!(checked[0].attributes().unwrap().get("value"), Some("l"));
+        assert_eq!(
+            group.select("label[for=size-1]")[0]
+                .children()
+                .last()
+                .map(ToString::to_string),
+            Some("Large".to_string())
+        );
+    }
+
+    #[test]
+    fn radio_ids_use_option_index() {
+        let group = Node::from(radio_group(
+            "size",
+            [("Extra large", "x l"), ("Small", "s&m")],
+        ));
+        let ids = group
+            .select("input")
+            .into_iter()
+            .map(|radio| radio.attributes().unwrap().get("id").unwrap().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(ids, ["size-0", "size-1"]);
+        assert_eq!(
+            group.select("label[for=size-1] input[value='s&m']").len(),
+            1
+        );
+    }
+
+    #[test]
+    fn autofocus_is_boolean() {
+        let input = text_input("q").autofocus();
+        assert_eq!(input.attributes().get("autofocus"), Some("autofocus"));
+        let input = input.set_autofocus(false);
+        assert_eq!(input.attributes().get("autofocus"), None);
+        let checkbox = checkbox("agree", false).set_autofocus(true);
+        assert_eq!(checkbox.attributes().get("autofocus"), Some("autofocus"));
+    }
+}
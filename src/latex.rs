@@ -0,0 +1,606 @@
+use crate::mathml::*;
+use crate::node::prelude::*;
+use std::fmt::Display;
+
+/// Converts LaTeX math, e.g. `\frac{-b \pm \sqrt{b^2 - 4ac}}{2a}`, to MathML for the content of
+/// a [`math`] element.
+///
+/// Supports numbers, letters, operators, groups, subscripts and superscripts, `\frac`, `\sqrt`,
+/// `\left` and `\right`, `\text`, `\mathrm`, `\operatorname`, spacing commands, greek letters,
+/// common symbols, functions and large operators, and the `matrix`, `pmatrix`, `bmatrix` and
+/// `vmatrix` environments.
+pub fn to_mathml(source: &str) -> Result<Node, LatexError> {
+    let mut parser = Parser {
+        chars: source.chars().collect(),
+        position: 0,
+    };
+    let nodes = parser.row(None)?;
+    match parser.peek() {
+        None => Ok(row(nodes)),
+        Some(c) => Err(parser.error(format!("unexpected {c:?}"))),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LatexError(String);
+
+impl Display for LatexError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "invalid LaTeX: {}", self.0)
+    }
+}
+
+impl std::error::Error for LatexError {}
+
+const GREEK: &[(&str, char)] = &[
+    ("alpha", 'α'),
+    ("beta", 'β'),
+    ("gamma", 'γ'),
+    ("delta", 'δ'),
+    ("epsilon", 'ϵ'),
+    ("varepsilon", 'ε'),
+    ("zeta", 'ζ'),
+    ("eta", 'η'),
+    ("theta", 'θ'),
+    ("vartheta", 'ϑ'),
+    ("iota", 'ι'),
+    ("kappa", 'κ'),
+    ("lambda", 'λ'),
+    ("mu", 'μ'),
+    ("nu", 'ν'),
+    ("xi", 'ξ'),
+    ("pi", 'π'),
+    ("rho", 'ρ'),
+    ("sigma", 'σ'),
+    ("tau", 'τ'),
+    ("upsilon", 'υ'),
+    ("phi", 'ϕ'),
+    ("varphi", 'φ'),
+    ("chi", 'χ'),
+    ("psi", 'ψ'),
+    ("omega", 'ω'),
+    ("infty", '∞'),
+    ("partial", '∂'),
+    ("nabla", '∇'),
+    ("emptyset", '∅'),
+    ("ell", 'ℓ'),
+    ("hbar", 'ℏ'),
+];
+
+/// Upright, unlike other single character identifiers.
+const UPPERCASE_GREEK: &[(&str, char)] = &[
+    ("Gamma", 'Γ'),
+    ("Delta", 'Δ'),
+    ("Theta", 'Θ'),
+    ("Lambda", 'Λ'),
+    ("Xi", 'Ξ'),
+    ("Pi", 'Π'),
+    ("Sigma", 'Σ'),
+    ("Upsilon", 'Υ'),
+    ("Phi", 'Φ'),
+    ("Psi", 'Ψ'),
+    ("Omega", 'Ω'),
+];
+
+const OPERATORS: &[(&str, char)] = &[
+    ("cdot", '⋅'),
+    ("times", '×'),
+    ("div", '÷'),
+    ("pm", '±'),
+    ("mp", '∓'),
+    ("ast", '∗'),
+    ("circ", '∘'),
+    ("leq", '≤'),
+    ("le", '≤'),
+    ("geq", '≥'),
+    ("ge", '≥'),
+    ("neq", '≠'),
+    ("ne", '≠'),
+    ("approx", '≈'),
+    ("equiv", '≡'),
+    ("sim", '∼'),
+    ("propto", '∝'),
+    ("to", '→'),
+    ("rightarrow", '→'),
+    ("leftarrow", '←'),
+    ("Rightarrow", '⇒'),
+    ("Leftarrow", '⇐'),
+    ("Leftrightarrow", '⇔'),
+    ("mapsto", '↦'),
+    ("in", '∈'),
+    ("notin", '∉'),
+    ("subset", '⊂'),
+    ("subseteq", '⊆'),
+    ("supset", '⊃'),
+    ("supseteq", '⊇'),
+    ("cup", '∪'),
+    ("cap", '∩'),
+    ("setminus", '∖'),
+    ("forall", '∀'),
+    ("exists", '∃'),
+    ("neg", '¬'),
+    ("land", '∧'),
+    ("lor", '∨'),
+    ("ldots", '…'),
+    ("cdots", '⋯'),
+    ("langle", '⟨'),
+    ("rangle", '⟩'),
+    ("lbrace", '{'),
+    ("rbrace", '}'),
+    ("{", '{'),
+    ("}", '}'),
+    ("|", '‖'),
+    ("%", '%'),
+    ("$", '$'),
+    ("#", '#'),
+    ("&", '&'),
+    ("_", '_'),
+];
+
+/// Large operators, with whether their limits are drawn above and below.
+const LARGE_OPERATORS: &[(&str, char, bool)] = &[
+    ("sum", '∑', true),
+    ("prod", '∏', true),
+    ("coprod", '∐', true),
+    ("bigcup", '⋃', true),
+    ("bigcap", '⋂', true),
+    ("int", '∫', false),
+    ("iint", '∬', false),
+    ("oint", '∮', false),
+];
+
+const FUNCTIONS: &[&str] = &[
+    "sin", "cos", "tan", "cot", "sec", "csc", "arcsin", "arccos", "arctan", "sinh", "cosh", "tanh",
+    "log", "ln", "exp", "det", "dim", "ker", "deg", "gcd", "arg",
+];
+
+/// Functions with limits drawn below.
+const LIMIT_FUNCTIONS: &[&str] = &["lim", "max", "min", "sup", "inf"];
+
+const SPACES: &[(&str, &str)] = &[
+    (",", "0.1667em"),
+    (":", "0.2222em"),
+    (">", "0.2222em"),
+    (";", "0.2778em"),
+    (" ", "0.25em"),
+    ("quad", "1em"),
+    ("qquad", "2em"),
+];
+
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+}
+
+impl Parser {
+    fn error(&self, message: impl Display) -> LatexError {
+        LatexError(format!("{message} at {}", self.position))
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), LatexError> {
+        self.skip_whitespace();
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(self.error(format!("expected {c:?}")))
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.position += 1;
+        }
+    }
+
+    fn starts_with(&self, s: &str) -> bool {
+        let mut chars = self.chars[self.position..].iter();
+        s.chars().all(|c| chars.next() == Some(&c))
+    }
+
+    /// Whether the input continues with `\{name}`, and not a longer command.
+    fn at_command(&self, name: &str) -> bool {
+        let end = self.position + 1 + name.chars().count();
+        self.starts_with(&format!("\\{name}"))
+            && !self.chars.get(end).is_some_and(char::is_ascii_alphabetic)
+    }
+
+    /// Reads the name of a command after its backslash, a run of letters or a single character.
+    fn command(&mut self) -> Result<String, LatexError> {
+        let start = self.position;
+        while self.peek().is_some_and(|c| c.is_ascii_alphabetic()) {
+            self.position += 1;
+        }
+        if self.position == start {
+            let c = self
+                .peek()
+                .ok_or_else(|| self.error("expected a command"))?;
+            self.position += 1;
+            return Ok(c.to_string());
+        }
+        Ok(self.chars[start..self.position].iter().collect())
+    }
+
+    /// Parses atoms until the end of the input, a closing brace, `&`, `\\`, `\end`, `\right` or
+    /// the `end` character.
+    fn row(&mut self, end: Option<char>) -> Result<Vec<Node>, LatexError> {
+        let mut nodes = Vec::new();
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                None | Some('}') | Some('&') => break,
+                c if c == end => break,
+                _ if self.starts_with("\\\\")
+                    || self.at_command("end")
+                    || self.at_command("right") =>
+                {
+                    break
+                }
+                _ => nodes.push(self.scripted()?),
+            }
+        }
+        Ok(nodes)
+    }
+
+    /// Parses an atom with its subscript and superscript.
+    fn scripted(&mut self) -> Result<Node, LatexError> {
+        let (base, limits) = self.atom()?;
+        let (mut subscript, mut superscript) = (None, None);
+        loop {
+            self.skip_whitespace();
+            let script = if self.eat('_') {
+                &mut subscript
+            } else if self.eat('^') {
+                &mut superscript
+            } else {
+                break;
+            };
+            if script.is_some() {
+                return Err(self.error("double script"));
+            }
+            *script = Some(self.argument()?);
+        }
+
+        Ok(match (subscript, superscript, limits) {
+            (None, None, _) => base,
+            (Some(sub), None, false) => msub().child(base).child(sub).into(),
+            (None, Some(sup), false) => msup().child(base).child(sup).into(),
+            (Some(sub), Some(sup), false) => msubsup().child(base).child(sub).child(sup).into(),
+            (Some(sub), None, true) => munder().child(base).child(sub).into(),
+            (None, Some(sup), true) => mover().child(base).child(sup).into(),
+            (Some(sub), Some(sup), true) => munderover().child(base).child(sub).child(sup).into(),
+        })
+    }
+
+    /// Parses the argument of a command or script, a group or a single token.
+    fn argument(&mut self) -> Result<Node, LatexError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(c) if c.is_ascii_digit() => {
+                self.position += 1;
+                Ok(mn().text(c).into())
+            }
+            None | Some('}' | '&' | '_' | '^') => Err(self.error("expected an argument")),
+            _ if self.starts_with("\\\\") => Err(self.error("expected an argument")),
+            _ => Ok(self.atom()?.0),
+        }
+    }
+
+    /// Parses an atom, with whether its scripts are limits drawn above and below it.
+    fn atom(&mut self) -> Result<(Node, bool), LatexError> {
+        let Some(c) = self.peek() else {
+            return Err(self.error("unexpected end"));
+        };
+        let node: Node = match c {
+            '{' => {
+                self.position += 1;
+                let nodes = self.row(None)?;
+                self.expect('}')?;
+                row(nodes)
+            }
+            // A script without a base.
+            '_' | '^' => mrow().into(),
+            '\\' => {
+                self.position += 1;
+                return self.command_atom();
+            }
+            c if c.is_ascii_digit() || c == '.' => self.number(),
+            c if c.is_alphabetic() => {
+                self.position += 1;
+                mi().text(c).into()
+            }
+            '-' => {
+                self.position += 1;
+                mo().text('−').into()
+            }
+            '\'' => {
+                self.position += 1;
+                mo().text('′').into()
+            }
+            '+' | '=' | '<' | '>' | ',' | ';' | ':' | '!' | '?' | '(' | ')' | '[' | ']' | '|'
+            | '/' | '*' => {
+                self.position += 1;
+                mo().text(c).into()
+            }
+            c => return Err(self.error(format!("unexpected {c:?}"))),
+        };
+        Ok((node, false))
+    }
+
+    fn number(&mut self) -> Node {
+        let start = self.position;
+        let mut decimal_point = false;
+        while let Some(c) = self.peek() {
+            let digit_follows = self
+                .chars
+                .get(self.position + 1)
+                .is_some_and(char::is_ascii_digit);
+            if c == '.' && !decimal_point && digit_follows {
+                decimal_point = true;
+            } else if !c.is_ascii_digit() {
+                break;
+            }
+            self.position += 1;
+        }
+        if self.position == start {
+            // A lone `.`.
+            self.position += 1;
+            return mo().text('.').into();
+        }
+        mn().text(self.chars[start..self.position].iter().collect::<String>())
+            .into()
+    }
+
+    fn command_atom(&mut self) -> Result<(Node, bool), LatexError> {
+        let name = self.command()?;
+        let lookup = |table: &[(&str, char)]| {
+            table
+                .iter()
+                .find(|(command, _)| *command == name)
+                .map(|(_, c)| *c)
+        };
+
+        if let Some(c) = lookup(GREEK) {
+            return Ok((mi().text(c).into(), false));
+        }
+        if let Some(c) = lookup(UPPERCASE_GREEK) {
+            return Ok((mi().mathvariant("normal").text(c).into(), false));
+        }
+        if let Some(c) = lookup(OPERATORS) {
+            return Ok((mo().text(c).into(), false));
+        }
+        if let Some((_, c, limits)) = LARGE_OPERATORS
+            .iter()
+            .find(|(command, ..)| *command == name)
+        {
+            return Ok((mo().text(c).into(), *limits));
+        }
+        if FUNCTIONS.contains(&name.as_str()) {
+            return Ok((mi().text(name).into(), false));
+        }
+        if LIMIT_FUNCTIONS.contains(&name.as_str()) {
+            return Ok((mi().text(name).into(), true));
+        }
+        if let Some((_, width)) = SPACES.iter().find(|(command, _)| *command == name) {
+            return Ok((mspace().width(width).into(), false));
+        }
+
+        let node = match name.as_str() {
+            "frac" => mfrac()
+                .child(self.argument()?)
+                .child(self.argument()?)
+                .into(),
+            "sqrt" => {
+                self.skip_whitespace();
+                if self.eat('[') {
+                    let index = self.row(Some(']'))?;
+                    self.expect(']')?;
+                    mroot().child(self.argument()?).child(row(index)).into()
+                } else {
+                    msqrt().child(self.argument()?).into()
+                }
+            }
+            "text" => mtext().text(self.text_argument()?).into(),
+            "mathrm" => mi()
+                .mathvariant("normal")
+                .text(self.text_argument()?)
+                .into(),
+            "operatorname" => mi().text(self.text_argument()?).into(),
+            "left" => {
+                let open = self.delimiter()?;
+                let mut nodes = self.row(None)?;
+                if !self.at_command("right") {
+                    return Err(self.error("expected \\right"));
+                }
+                self.position += "\\right".len();
+                let close = self.delimiter()?;
+                nodes.insert(0, open);
+                nodes.push(close);
+                mrow().children(nodes).into()
+            }
+            "begin" => self.environment()?,
+            name => return Err(self.error(format!("unknown command \\{name}"))),
+        };
+        Ok((node, false))
+    }
+
+    /// Reads the braced argument of `\text` and similar commands as plain text.
+    fn text_argument(&mut self) -> Result<String, LatexError> {
+        self.expect('{')?;
+        let mut text = String::new();
+        let mut depth = 0;
+        loop {
+            match self.peek() {
+                None => return Err(self.error("expected '}'")),
+                Some('}') if depth == 0 => break,
+                Some(c) => {
+                    match c {
+                        '{' => depth += 1,
+                        '}' => depth -= 1,
+                        _ => {}
+                    }
+                    text.push(c);
+                }
+            }
+            self.position += 1;
+        }
+        self.position += 1;
+        Ok(text)
+    }
+
+    /// Parses the delimiter after `\left` or `\right`, `.` meaning none.
+    fn delimiter(&mut self) -> Result<Node, LatexError> {
+        self.skip_whitespace();
+        let delimiter = match self.peek() {
+            Some('.') => {
+                self.position += 1;
+                return Ok(Node::fragment(Vec::<Node>::new()));
+            }
+            Some(c @ ('(' | ')' | '[' | ']' | '|' | '/')) => {
+                self.position += 1;
+                c
+            }
+            Some('\\') => {
+                self.position += 1;
+                let name = self.command()?;
+                OPERATORS
+                    .iter()
+                    .find(|(command, _)| *command == name)
+                    .filter(|(_, c)| matches!(c, '{' | '}' | '‖' | '⟨' | '⟩'))
+                    .map(|(_, c)| *c)
+                    .ok_or_else(|| self.error(format!("invalid delimiter \\{name}")))?
+            }
+            _ => return Err(self.error("expected a delimiter")),
+        };
+        Ok(mo().text(delimiter).into())
+    }
+
+    /// Parses a matrix environment after `\begin`.
+    fn environment(&mut self) -> Result<Node, LatexError> {
+        let name = self.text_argument()?;
+        let (open, close) = match name.as_str() {
+            "matrix" => (None, None),
+            "pmatrix" => (Some('('), Some(')')),
+            "bmatrix" => (Some('['), Some(']')),
+            "vmatrix" => (Some('|'), Some('|')),
+            name => return Err(self.error(format!("unknown environment {name}"))),
+        };
+
+        let mut rows = Vec::new();
+        loop {
+            let mut cells = vec![self.row(None)?];
+            while self.eat('&') {
+                cells.push(self.row(None)?);
+            }
+            rows.push(cells);
+            if self.starts_with("\\\\") {
+                self.position += 2;
+            } else if self.at_command("end") {
+                self.position += "\\end".len();
+                break;
+            } else {
+                return Err(self.error("expected \\end"));
+            }
+        }
+        if self.text_argument()? != name {
+            return Err(self.error(format!("expected \\end{{{name}}}")));
+        }
+        // A trailing `\\` doesn't start a row.
+        if rows.len() > 1
+            && rows
+                .last()
+                .is_some_and(|cells| matches!(&cells[..], [cell] if cell.is_empty()))
+        {
+            rows.pop();
+        }
+
+        let table = mtable().children(
+            rows.into_iter()
+                .map(|cells| mtr().children(cells.into_iter().map(|cell| mtd().children(cell)))),
+        );
+        Ok(mrow()
+            .children(open.map(|open| mo().text(open)))
+            .child(table)
+            .children(close.map(|close| mo().text(close)))
+            .into())
+    }
+}
+
+/// A single node, or an `mrow` of several.
+fn row(mut nodes: Vec<Node>) -> Node {
+    if nodes.len() == 1 {
+        nodes.remove(0)
+    } else {
+        mrow().children(nodes).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts() {
+        let cases = [
+            (r"\frac{1}{2}", "<mfrac><mn>1</mn><mn>2</mn></mfrac>"),
+            (r"\sqrt{x}", "<msqrt><mi>x</mi></msqrt>"),
+            (r"\sqrt[3]{x}", "<mroot><mi>x</mi><mn>3</mn></mroot>"),
+            ("x_1", "<msub><mi>x</mi><mn>1</mn></msub>"),
+            ("x^2", "<msup><mi>x</mi><mn>2</mn></msup>"),
+            ("x_i^2", "<msubsup><mi>x</mi><mi>i</mi><mn>2</mn></msubsup>"),
+            ("12.5+x", "<mrow><mn>12.5</mn><mo>+</mo><mi>x</mi></mrow>"),
+            (
+                r"\sum_{i=1}^n i",
+                "<mrow><munderover><mo>∑</mo><mrow><mi>i</mi><mo>=</mo><mn>1</mn></mrow><mi>n</mi></munderover><mi>i</mi></mrow>",
+            ),
+            (
+                r"\left( x \right)",
+                "<mrow><mo>(</mo><mi>x</mi><mo>)</mo></mrow>",
+            ),
+            (r"\left. x \right|", "<mrow><mi>x</mi><mo>|</mo></mrow>"),
+            (
+                r"\begin{pmatrix} a & b \\ c & d \end{pmatrix}",
+                "<mrow><mo>(</mo><mtable><mtr><mtd><mi>a</mi></mtd><mtd><mi>b</mi></mtd></mtr><mtr><mtd><mi>c</mi></mtd><mtd><mi>d</mi></mtd></mtr></mtable><mo>)</mo></mrow>",
+            ),
+        ];
+        for (source, expected) in cases {
+            assert_eq!(to_mathml(source).unwrap().to_string(), expected, "{source}");
+        }
+    }
+
+    #[test]
+    fn rejects_invalid() {
+        let cases = [
+            ("{x", "expected '}' at 2"),
+            ("x}", "unexpected '}' at 1"),
+            (r"\frac{1}", "expected an argument at 8"),
+            (r"\foo", r"unknown command \foo at 4"),
+            (r"\left( x", r"expected \right at 8"),
+            (r"\begin{foo}", "unknown environment foo at 11"),
+            (
+                r"\begin{matrix} a \end{bmatrix}",
+                r"expected \end{matrix} at 30",
+            ),
+        ];
+        for (source, expected) in cases {
+            let error = to_mathml(source).unwrap_err();
+            assert_eq!(
+                error.to_string(),
+                format!("invalid LaTeX: {expected}"),
+                "{source}"
+            );
+        }
+    }
+}
@@ -1,15 +1,65 @@
-pub use attributes::Attributes;
+// Lets the derive macros, which refer to `::html_builder`, be used inside this crate.
+extern crate self as html_builder;
+
+pub use assets::asset;
+pub use attributes::{
+    is_valid_custom_element_name, is_valid_data_key, Attributes, Autocapitalize, ClassName,
+    ContentEditable, DataError, Dir, EnterKeyHint, InputMode, Popover, ShadowRootMode,
+};
+pub use global_attributes::Event;
+pub use html_builder_derive::{Form, SelectOptions, Table};
 pub use node::prelude::*;
 
+pub mod a11y;
+pub mod aria;
+mod aria_attributes;
+pub mod assets;
 mod attributes;
+mod controls;
+pub mod csrf;
+mod custom;
+pub mod diff;
+mod document;
+pub mod forms;
 mod gen;
+mod global_attributes;
+mod head;
+pub mod htmx;
+mod inputs;
+pub mod latex;
 mod macros;
+pub mod mathml;
 mod node;
+pub mod scoped;
+pub mod select;
+pub mod style;
+pub mod svg;
+pub mod table;
+pub mod validate;
+pub mod visit;
 
 pub mod prelude {
     // TODO: make structs private
+    pub use super::assets::asset;
+    pub use super::attributes::{
+        Autocapitalize, ContentEditable, Dir, EnterKeyHint, InputMode, Popover, ShadowRootMode,
+    };
+    pub use super::controls::*;
+    pub use super::custom::*;
+    pub use super::forms::{FormFields, SelectOptions};
     pub use super::gen::*;
+    pub use super::global_attributes::Event;
+    pub use super::head::*;
+    pub use super::htmx::{CustomEvent, HtmxEvent};
+    pub use super::inputs::*;
     pub use super::node::prelude::*;
+    pub use super::scoped::ScopedCss;
+    pub use super::select::Selector;
+    pub use super::table::{
+        caption, col, colgroup, column, table, table_from, tbody, td, tfoot, th, thead, tr, Scope,
+        TableRow,
+    };
+    pub use html_builder_derive::{Form, SelectOptions, Table};
 }
 
 pub mod html {
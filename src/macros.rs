@@ -1,11 +1,15 @@
 #[macro_export]
 macro_rules! element_struct {
     ($name:ident, $tag_name:ident, $doc:literal) => {
+        $crate::element_struct!($name, stringify!($tag_name), $doc);
+    };
+    // Tag names that aren't valid identifiers, such as `use`.
+    ($name:ident, $tag_name:expr, $doc:literal) => {
         #[allow(clippy::empty_docs)]
         #[doc = $doc]
         #[derive(Debug, Clone)]
         pub struct $name {
-            element: GenericElement,
+            pub(crate) element: $crate::GenericElement,
         }
 
         impl std::fmt::Display for $name {
@@ -14,13 +18,13 @@ macro_rules! element_struct {
             }
         }
 
-        impl From<$name> for Node {
+        impl From<$name> for $crate::Node {
             fn from(value: $name) -> Self {
                 Self::from(value.element)
             }
         }
 
-        impl Element for $name {
+        impl $crate::Element for $name {
             fn attribute(
                 mut self,
                 key: impl std::fmt::Display,
@@ -28,41 +32,44 @@ macro_rules! element_struct {
             ) -> Self {
                 self.element
                     .attributes
-                    .0
-                    .entry(key.to_string())
-                    .and_modify(|entry| {
-                        if key.to_string() == "class" {
-                            entry.push_str(&format!(" {value}"))
-                        } else {
-                            *entry = value.to_string()
-                        }
-                    })
-                    .or_insert_with(|| value.to_string());
+                    .set(key.to_string(), value.to_string());
                 self
             }
 
             fn remove_attribute(mut self, key: impl std::fmt::Display) -> Self {
-                self.element.attributes.0.remove(&key.to_string());
+                self.element.attributes.remove(&key.to_string());
                 self
             }
+
+            fn attributes(&self) -> &$crate::Attributes {
+                &self.element.attributes
+            }
+
+            fn attributes_mut(&mut self) -> &mut $crate::Attributes {
+                &mut self.element.attributes
+            }
+
+            fn as_generic_element(&self) -> ::std::option::Option<&$crate::GenericElement> {
+                Some(&self.element)
+            }
         }
 
-        impl Children for $name {
+        impl $crate::Children for $name {
             fn child<T>(mut self, child: T) -> Self
             where
-                Node: From<T>,
+                $crate::Node: From<T>,
             {
-                self.element.children.push(child.into());
+                self.element.push_child(child.into());
                 self
             }
         }
 
         impl $name {
             #[allow(dead_code)]
-            fn new_empty() -> Self {
+            pub(crate) fn new_empty() -> Self {
                 $name {
-                    element: GenericElement {
-                        tag_name: stringify!($tag_name).to_string(),
+                    element: $crate::GenericElement {
+                        tag_name: $tag_name.to_string(),
                         attributes: $crate::Attributes::default(),
                         children: Vec::new(),
                     },
@@ -76,13 +83,14 @@ macro_rules! element_struct {
 macro_rules! void_element_struct {
     ($name:ident, $tag_name:ident, $doc:literal) => {
         #[doc = $doc]
+        #[derive(Debug, Clone)]
         pub struct $name {
             element: VoidElement,
         }
 
         impl $name {
             #[allow(dead_code)]
-            fn new_empty() -> Self {
+            pub(crate) fn new_empty() -> Self {
                 Self {
                     element: VoidElement {
                         attributes: $crate::Attributes::default(),
@@ -106,21 +114,50 @@ macro_rules! void_element_struct {
             ) -> Self {
                 self.element
                     .attributes
-                    .0
-                    .entry(key.to_string())
-                    .and_modify(|entry| {
-                        if key.to_string() == "class" {
-                            entry.push_str(&format!(" {value}"))
-                        } else {
-                            *entry = value.to_string()
-                        }
-                    })
-                    .or_insert_with(|| value.to_string());
+                    .set(key.to_string(), value.to_string());
                 self
             }
 
             fn remove_attribute(mut self, key: impl std::fmt::Display) -> Self {
-                self.element.attributes.0.remove(&key.to_string());
+                self.element.attributes.remove(&key.to_string());
+                self
+            }
+
+            fn attributes(&self) -> &$crate::Attributes {
+                &self.element.attributes
+            }
+
+            fn attributes_mut(&mut self) -> &mut $crate::Attributes {
+                &mut self.element.attributes
+            }
+        }
+    };
+}
+
+/// Defines a struct for a custom element like the generated elements, checking the tag name at
+/// compile time: `custom_element!(StarRating, "star-rating", "A rating out of five stars.");`.
+/// The struct gets a `StarRating::new()` constructor, typed setters are added with
+/// [`element_attribute!`] and [`element_boolean_attribute!`].
+#[macro_export]
+macro_rules! custom_element {
+    ($name:ident, $tag_name:literal, $doc:literal) => {
+        const _: () = assert!(
+            $crate::is_valid_custom_element_name($tag_name),
+            concat!("invalid custom element name: ", $tag_name)
+        );
+
+        $crate::element_struct!($name, $tag_name, $doc);
+
+        impl $name {
+            /// An element without attributes or children.
+            #[allow(clippy::new_without_default)]
+            pub fn new() -> Self {
+                Self::new_empty()
+            }
+
+            /// Adds a declarative shadow root, created with `shadow_root`.
+            pub fn shadow_root(mut self, root: $crate::prelude::Template) -> Self {
+                self.element.children.insert(0, root.into());
                 self
             }
         }
@@ -134,7 +171,7 @@ macro_rules! element_attribute {
             #[doc = $doc]
             #[allow(clippy::empty_docs)]
             pub fn $method_name(self, value: impl std::fmt::Display) -> Self {
-                self.attribute($html_name, value)
+                $crate::Element::attribute(self, $html_name, value)
             }
         }
     };
@@ -147,16 +184,72 @@ macro_rules! element_boolean_attribute {
             #[doc = $doc]
             #[allow(clippy::empty_docs)]
             pub fn $method_name(self) -> Self {
-                self.attribute($html_name, $html_name)
+                $crate::Element::attribute(self, $html_name, $html_name)
             }
 
             pub fn $set_method_name(self, value: bool) -> Self {
                 if value {
                     self.$method_name()
                 } else {
-                    self.remove_attribute($html_name)
+                    $crate::Element::remove_attribute(self, $html_name)
+                }
+            }
+        }
+    };
+}
+
+/// Builds a class list from class names, optionally followed by `=> condition`, skipping
+/// duplicates and `None` values, similar to `clsx`:
+/// `classes!("button", "button--active" => active, error_class)`.
+#[macro_export]
+macro_rules! classes {
+    ($($class:expr $(=> $condition:expr)?),* $(,)?) => {{
+        let mut classes = ::std::vec::Vec::<::std::string::String>::new();
+        $(
+            if true $(&& $condition)? {
+                $crate::ClassName::push_class(&$class, &mut classes);
+            }
+        )*
+        classes.join(" ")
+    }};
+}
+
+/// Defines an enum of keywords with `Display` and `FromStr` implementations.
+macro_rules! keywords {
+    ($(#[$meta:meta])* $name:ident { $($variant:ident => $keyword:literal,)* }) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($variant,)*
+        }
+
+        impl $name {
+            pub const ALL: &'static [Self] = &[$(Self::$variant,)*];
+
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $keyword,)*
+                }
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "{}", self.as_str())
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = String;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    $($keyword => Ok(Self::$variant),)*
+                    _ => Err(format!(concat!("invalid ", stringify!($name), ": {}"), s)),
                 }
             }
         }
     };
 }
+
+pub(crate) use keywords;
@@ -0,0 +1,406 @@
+use crate::node::prelude::*;
+use crate::{element_attribute, element_struct};
+pub const MATHML_NAMESPACE: &str = "http://www.w3.org/1998/Math/MathML";
+#[doc = r" Presentation attributes, which every element accepts."]
+pub trait Presentation: Element {
+    #[doc = "`true` to render the element in display style, with full size fractions and limits above and below large operators."]
+    fn displaystyle(self, value: impl std::fmt::Display) -> Self {
+        self.attribute("displaystyle", value)
+    }
+    #[doc = "The script level of the element, which determines its font size, e.g. `+1` or `0`."]
+    fn scriptlevel(self, value: impl std::fmt::Display) -> Self {
+        self.attribute("scriptlevel", value)
+    }
+    #[doc = "The style of the element's text, only `normal` is supported on single character `mi` elements, which are italic otherwise."]
+    fn mathvariant(self, value: impl std::fmt::Display) -> Self {
+        self.attribute("mathvariant", value)
+    }
+    #[doc = "The color of the element's text."]
+    fn mathcolor(self, value: impl std::fmt::Display) -> Self {
+        self.attribute("mathcolor", value)
+    }
+    #[doc = "The background color of the element."]
+    fn mathbackground(self, value: impl std::fmt::Display) -> Self {
+        self.attribute("mathbackground", value)
+    }
+    #[doc = "The font size of the element's text."]
+    fn mathsize(self, value: impl std::fmt::Display) -> Self {
+        self.attribute("mathsize", value)
+    }
+}
+element_struct!(Math, "math", "The top-level element of a MathML formula.");
+element_attribute ! (Math , display , "display" , "`block` to render the formula on its own line, or `inline` (the default) to render it within text.") ;
+element_attribute!(
+    Math,
+    alttext,
+    "alttext",
+    "A text alternative for the formula, for user agents that can't render it."
+);
+impl Presentation for Math {}
+pub fn math() -> Math {
+    Math::new_empty().attribute("xmlns", MATHML_NAMESPACE)
+}
+element_struct ! (Semantics , "semantics" , "Associates annotations, such as the source of a formula, with its presentation, the first child.") ;
+impl Presentation for Semantics {}
+pub fn semantics() -> Semantics {
+    Semantics::new_empty()
+}
+element_struct!(
+    Annotation,
+    "annotation",
+    "A textual annotation of a formula, such as its LaTeX source."
+);
+element_attribute!(
+    Annotation,
+    encoding,
+    "encoding",
+    "The format of the annotation, e.g. `application/x-tex`."
+);
+impl Presentation for Annotation {}
+pub fn annotation() -> Annotation {
+    Annotation::new_empty()
+}
+element_struct!(
+    AnnotationXml,
+    "annotation-xml",
+    "An XML annotation of a formula, such as content MathML or HTML."
+);
+element_attribute!(
+    AnnotationXml,
+    encoding,
+    "encoding",
+    "The format of the annotation, e.g. `text/html` or `MathML-Content`."
+);
+impl Presentation for AnnotationXml {}
+pub fn annotation_xml() -> AnnotationXml {
+    AnnotationXml::new_empty()
+}
+element_struct ! (Mi , "mi" , "An identifier, such as a variable or function name. Single characters are rendered in italics.") ;
+impl Presentation for Mi {}
+pub fn mi() -> Mi {
+    Mi::new_empty()
+}
+element_struct!(Mn, "mn", "A numeric literal.");
+impl Presentation for Mn {}
+pub fn mn() -> Mn {
+    Mn::new_empty()
+}
+element_struct!(
+    Mo,
+    "mo",
+    "An operator, fence or separator, spaced and stretched according to the operator dictionary."
+);
+element_attribute!(
+    Mo,
+    form,
+    "form",
+    "The position of the operator in its row, `prefix`, `infix` or `postfix`."
+);
+element_attribute!(
+    Mo,
+    fence,
+    "fence",
+    "`true` if the operator is a fence, such as a parenthesis."
+);
+element_attribute!(
+    Mo,
+    separator,
+    "separator",
+    "`true` if the operator is a separator, such as a comma."
+);
+element_attribute!(Mo, lspace, "lspace", "The space before the operator.");
+element_attribute!(Mo, rspace, "rspace", "The space after the operator.");
+element_attribute!(
+    Mo,
+    stretchy,
+    "stretchy",
+    "`true` if the operator stretches to the size of its row."
+);
+element_attribute!(
+    Mo,
+    symmetric,
+    "symmetric",
+    "`true` if a stretchy operator stretches symmetrically around the math axis."
+);
+element_attribute!(
+    Mo,
+    maxsize,
+    "maxsize",
+    "The maximum size of a stretchy operator."
+);
+element_attribute!(
+    Mo,
+    minsize,
+    "minsize",
+    "The minimum size of a stretchy operator."
+);
+element_attribute!(
+    Mo,
+    largeop,
+    "largeop",
+    "`true` if the operator is drawn larger when `displaystyle` is true, like sums and integrals."
+);
+element_attribute!(
+    Mo,
+    movablelimits,
+    "movablelimits",
+    "`true` if limits of the operator are drawn as scripts when `displaystyle` is false."
+);
+impl Presentation for Mo {}
+pub fn mo() -> Mo {
+    Mo::new_empty()
+}
+element_struct!(Ms, "ms", "A string literal.");
+impl Presentation for Ms {}
+pub fn ms() -> Ms {
+    Ms::new_empty()
+}
+element_struct!(Mtext, "mtext", "Arbitrary text without notational meaning.");
+impl Presentation for Mtext {}
+pub fn mtext() -> Mtext {
+    Mtext::new_empty()
+}
+element_struct!(Mspace, "mspace", "A blank space of a given size.");
+element_attribute!(Mspace, width, "width", "The width of the space.");
+element_attribute!(
+    Mspace,
+    height,
+    "height",
+    "The height of the space above the baseline."
+);
+element_attribute!(
+    Mspace,
+    depth,
+    "depth",
+    "The depth of the space below the baseline."
+);
+impl Presentation for Mspace {}
+pub fn mspace() -> Mspace {
+    Mspace::new_empty()
+}
+element_struct!(Mrow, "mrow", "Groups subexpressions horizontally.");
+impl Presentation for Mrow {}
+pub fn mrow() -> Mrow {
+    Mrow::new_empty()
+}
+element_struct!(
+    Mfrac,
+    "mfrac",
+    "A fraction, its first child is the numerator and its second the denominator."
+);
+element_attribute!(
+    Mfrac,
+    linethickness,
+    "linethickness",
+    "The thickness of the fraction bar, `0` for binomial coefficients."
+);
+impl Presentation for Mfrac {}
+pub fn mfrac() -> Mfrac {
+    Mfrac::new_empty()
+}
+element_struct!(Msqrt, "msqrt", "A square root of its children.");
+impl Presentation for Msqrt {}
+pub fn msqrt() -> Msqrt {
+    Msqrt::new_empty()
+}
+element_struct!(
+    Mroot,
+    "mroot",
+    "A root, its first child is the base and its second the index."
+);
+impl Presentation for Mroot {}
+pub fn mroot() -> Mroot {
+    Mroot::new_empty()
+}
+element_struct!(
+    Mstyle,
+    "mstyle",
+    "Changes the style of its children, such as `displaystyle` and `scriptlevel`."
+);
+impl Presentation for Mstyle {}
+pub fn mstyle() -> Mstyle {
+    Mstyle::new_empty()
+}
+element_struct!(
+    Merror,
+    "merror",
+    "Displays its children as an error message."
+);
+impl Presentation for Merror {}
+pub fn merror() -> Merror {
+    Merror::new_empty()
+}
+element_struct!(Mpadded, "mpadded", "Adjusts the space around its children.");
+element_attribute!(Mpadded, width, "width", "The width of the content.");
+element_attribute!(
+    Mpadded,
+    height,
+    "height",
+    "The height of the content above the baseline."
+);
+element_attribute!(
+    Mpadded,
+    depth,
+    "depth",
+    "The depth of the content below the baseline."
+);
+element_attribute!(Mpadded, lspace, "lspace", "The space before the content.");
+element_attribute!(
+    Mpadded,
+    voffset,
+    "voffset",
+    "The vertical offset of the content."
+);
+impl Presentation for Mpadded {}
+pub fn mpadded() -> Mpadded {
+    Mpadded::new_empty()
+}
+element_struct!(
+    Mphantom,
+    "mphantom",
+    "Takes up the space of its children without rendering them."
+);
+impl Presentation for Mphantom {}
+pub fn mphantom() -> Mphantom {
+    Mphantom::new_empty()
+}
+element_struct!(
+    Msub,
+    "msub",
+    "A base with a subscript, its first and second children."
+);
+impl Presentation for Msub {}
+pub fn msub() -> Msub {
+    Msub::new_empty()
+}
+element_struct!(
+    Msup,
+    "msup",
+    "A base with a superscript, its first and second children."
+);
+impl Presentation for Msup {}
+pub fn msup() -> Msup {
+    Msup::new_empty()
+}
+element_struct!(
+    Msubsup,
+    "msubsup",
+    "A base with a subscript and a superscript, its first, second and third children."
+);
+impl Presentation for Msubsup {}
+pub fn msubsup() -> Msubsup {
+    Msubsup::new_empty()
+}
+element_struct!(
+    Munder,
+    "munder",
+    "A base with an underscript, its first and second children."
+);
+element_attribute!(
+    Munder,
+    accentunder,
+    "accentunder",
+    "`true` if the underscript is an accent, which is drawn closer to the base."
+);
+impl Presentation for Munder {}
+pub fn munder() -> Munder {
+    Munder::new_empty()
+}
+element_struct!(
+    Mover,
+    "mover",
+    "A base with an overscript, its first and second children."
+);
+element_attribute!(
+    Mover,
+    accent,
+    "accent",
+    "`true` if the overscript is an accent, which is drawn closer to the base."
+);
+impl Presentation for Mover {}
+pub fn mover() -> Mover {
+    Mover::new_empty()
+}
+element_struct!(
+    Munderover,
+    "munderover",
+    "A base with an underscript and an overscript, its first, second and third children."
+);
+element_attribute!(
+    Munderover,
+    accent,
+    "accent",
+    "`true` if the overscript is an accent, which is drawn closer to the base."
+);
+element_attribute!(
+    Munderover,
+    accentunder,
+    "accentunder",
+    "`true` if the underscript is an accent, which is drawn closer to the base."
+);
+impl Presentation for Munderover {}
+pub fn munderover() -> Munderover {
+    Munderover::new_empty()
+}
+element_struct ! (Mmultiscripts , "mmultiscripts" , "A base with any number of subscript and superscript pairs, prescripts follow an `mprescripts` child.") ;
+impl Presentation for Mmultiscripts {}
+pub fn mmultiscripts() -> Mmultiscripts {
+    Mmultiscripts::new_empty()
+}
+element_struct!(
+    Mprescripts,
+    "mprescripts",
+    "Separates the postscripts from the prescripts of an `mmultiscripts` element."
+);
+impl Presentation for Mprescripts {}
+pub fn mprescripts() -> Mprescripts {
+    Mprescripts::new_empty()
+}
+element_struct!(Mtable, "mtable", "A table or matrix of `mtr` rows.");
+impl Presentation for Mtable {}
+pub fn mtable() -> Mtable {
+    Mtable::new_empty()
+}
+element_struct!(Mtr, "mtr", "A row of an `mtable`.");
+impl Presentation for Mtr {}
+pub fn mtr() -> Mtr {
+    Mtr::new_empty()
+}
+element_struct!(Mtd, "mtd", "A cell of an `mtable` row.");
+element_attribute!(
+    Mtd,
+    columnspan,
+    "columnspan",
+    "The number of columns the cell spans."
+);
+element_attribute!(
+    Mtd,
+    rowspan,
+    "rowspan",
+    "The number of rows the cell spans."
+);
+impl Presentation for Mtd {}
+pub fn mtd() -> Mtd {
+    Mtd::new_empty()
+}
+element_struct!(
+    Maction,
+    "maction",
+    "Binds actions to its children, only the selected child is rendered."
+);
+element_attribute!(
+    Maction,
+    actiontype,
+    "actiontype",
+    "The action, e.g. `toggle`."
+);
+element_attribute!(
+    Maction,
+    selection,
+    "selection",
+    "The index of the rendered child, `1` by default."
+);
+impl Presentation for Maction {}
+pub fn maction() -> Maction {
+    Maction::new_empty()
+}
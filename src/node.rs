@@ -1,6 +1,14 @@
-use crate::Attributes;
+use crate::{
+    aria::Role,
+    aria_attributes::aria_attributes,
+    attributes::{is_valid_data_key, DataError},
+    global_attributes::global_attributes,
+    htmx::HxEvent,
+    style::InlineStyle,
+    Attributes, Event,
+};
 use html_escape::encode_safe as escape;
-use std::fmt::Display;
+use std::{borrow::Cow, fmt::Display};
 
 #[derive(Debug, Clone)]
 pub enum Node {
@@ -8,6 +16,108 @@ pub enum Node {
     VoidElement(VoidElement),
     Text(String),
     RawText(String),
+    /// Rendered as `<!--...-->`, with `--` escaped so the text cannot end the comment early.
+    Comment(String),
+    /// Rendered as `<!DOCTYPE ...>`, see [`Node::doctype`].
+    Doctype(String),
+    /// Siblings without a wrapper element, flattened when added with [`Children::child`].
+    Fragment(Vec<Node>),
+}
+
+impl Node {
+    /// The HTML5 doctype, `<!DOCTYPE html>`.
+    pub fn doctype() -> Self {
+        Self::Doctype("html".to_string())
+    }
+
+    pub fn comment(text: impl Display) -> Self {
+        Self::Comment(text.to_string())
+    }
+
+    pub fn fragment<T>(nodes: impl IntoIterator<Item = T>) -> Self
+    where
+        Node: From<T>,
+    {
+        Self::Fragment(nodes.into_iter().map(Node::from).collect())
+    }
+
+    /// Wraps nodes in a conditional comment such as `<!--[if lt IE 9]>...<![endif]-->`, e.g.
+    /// `Node::conditional_comment("lt IE 9", [script("html5shiv.js")])`.
+    pub fn conditional_comment<T>(
+        condition: impl Display,
+        nodes: impl IntoIterator<Item = T>,
+    ) -> Self
+    where
+        Node: From<T>,
+    {
+        let condition = escape_comment(&condition.to_string()).replace(']', "");
+        let mut fragment = vec![Self::RawText(format!("<!--[if {condition}]>"))];
+        fragment.extend(nodes.into_iter().map(Node::from));
+        fragment.push(Self::RawText("<![endif]-->".to_string()));
+        Self::Fragment(fragment)
+    }
+
+    pub fn tag_name(&self) -> Option<&str> {
+        match self {
+            Self::Element(element) => Some(&element.tag_name),
+            Self::VoidElement(element) => Some(&element.tag_name),
+            _ => None,
+        }
+    }
+
+    pub fn attributes(&self) -> Option<&Attributes> {
+        match self {
+            Self::Element(element) => Some(&element.attributes),
+            Self::VoidElement(element) => Some(&element.attributes),
+            _ => None,
+        }
+    }
+
+    pub fn children(&self) -> &[Node] {
+        match self {
+            Self::Element(element) => &element.children,
+            Self::Fragment(nodes) => nodes,
+            _ => &[],
+        }
+    }
+}
+
+/// The element children of a node, each with a selector path extending `path`. Fragments are
+/// flattened, as their children take their place, so `:nth-child` counts the element siblings
+/// the browser sees.
+pub(crate) fn child_paths<'a>(path: &str, children: &'a [Node]) -> Vec<(&'a Node, String)> {
+    fn flatten<'a>(children: &'a [Node], elements: &mut Vec<&'a Node>) {
+        for child in children {
+            match child {
+                Node::Fragment(nodes) => flatten(nodes, elements),
+                child if child.tag_name().is_some() => elements.push(child),
+                _ => {}
+            }
+        }
+    }
+
+    let prefix = if path.is_empty() {
+        String::new()
+    } else {
+        format!("{path} > ")
+    };
+    let mut elements = Vec::new();
+    flatten(children, &mut elements);
+    let siblings = elements.len();
+
+    elements
+        .into_iter()
+        .enumerate()
+        .map(|(index, child)| {
+            let tag_name = child.tag_name().unwrap_or_default();
+            let path = if siblings == 1 {
+                format!("{prefix}{tag_name}")
+            } else {
+                format!("{prefix}{tag_name}:nth-child({})", index + 1)
+            };
+            (child, path)
+        })
+        .collect()
 }
 
 impl From<GenericElement> for Node {
@@ -22,6 +132,73 @@ impl From<VoidElement> for Node {
     }
 }
 
+/// `None` becomes an empty fragment, so adding it as a child adds nothing.
+impl<T> From<Option<T>> for Node
+where
+    Node: From<T>,
+{
+    fn from(value: Option<T>) -> Self {
+        match value {
+            Some(value) => value.into(),
+            None => Self::Fragment(Vec::new()),
+        }
+    }
+}
+
+impl<T> From<Vec<T>> for Node
+where
+    Node: From<T>,
+{
+    fn from(value: Vec<T>) -> Self {
+        Self::fragment(value)
+    }
+}
+
+macro_rules! text_from {
+    ($($type:ty),*) => {
+        $(
+            impl From<$type> for Node {
+                fn from(value: $type) -> Self {
+                    Self::Text(value.to_string())
+                }
+            }
+        )*
+    };
+}
+
+// Floats use `Display`, which doesn't depend on the locale and never uses exponents.
+text_from!(
+    &str, String, &String, char, bool, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128,
+    usize, f32, f64
+);
+
+impl From<Cow<'_, str>> for Node {
+    fn from(value: Cow<'_, str>) -> Self {
+        Self::Text(value.into_owned())
+    }
+}
+
+/// Trusted HTML that is rendered without escaping, e.g. `.child(PreEscaped(markdown_html))`.
+///
+/// Only wrap markup that is known to be safe, such as the output of a sanitizer or this crate.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct PreEscaped<T: AsRef<str>>(pub T);
+
+/// Owned trusted HTML, see [`PreEscaped`].
+pub type Markup = PreEscaped<String>;
+
+impl<T: AsRef<str>> Display for PreEscaped<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.0.as_ref())
+    }
+}
+
+impl<T: AsRef<str>> From<PreEscaped<T>> for Node {
+    fn from(value: PreEscaped<T>) -> Self {
+        Self::RawText(value.0.as_ref().to_string())
+    }
+}
+
 impl Display for Node {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
@@ -29,10 +206,28 @@ impl Display for Node {
             Self::VoidElement(element) => write!(f, "{element}"),
             Self::Text(text) => write!(f, "{}", escape(text)),
             Self::RawText(text) => write!(f, "{text}"),
+            Self::Comment(text) => write!(f, "<!--{}-->", escape_comment(text)),
+            Self::Doctype(doctype) => write!(f, "<!DOCTYPE {doctype}>"),
+            Self::Fragment(nodes) => nodes.iter().try_for_each(|node| write!(f, "{node}")),
         }
     }
 }
 
+/// Breaks up `--` and the other sequences that would end a comment or make it invalid.
+fn escape_comment(text: &str) -> String {
+    let mut escaped = text.to_string();
+    while escaped.contains("--") {
+        escaped = escaped.replace("--", "- -");
+    }
+    if escaped.starts_with('>') || escaped.starts_with("->") {
+        escaped.insert(0, ' ');
+    }
+    if escaped.ends_with('-') {
+        escaped.push(' ');
+    }
+    escaped
+}
+
 #[derive(Debug, Clone)]
 pub struct GenericElement {
     pub tag_name: String,
@@ -42,6 +237,9 @@ pub struct GenericElement {
 
 impl Display for GenericElement {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if matches!(self.tag_name.as_str(), "svg" | "math") {
+            return self.fmt_foreign(f);
+        }
         let Self {
             attributes,
             children,
@@ -57,6 +255,57 @@ impl Display for GenericElement {
     }
 }
 
+/// Formats a node inside SVG or MathML content, where elements without children self-close.
+fn fmt_foreign(node: &Node, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    match node {
+        Node::Element(element) => element.fmt_foreign(f),
+        Node::Fragment(nodes) => nodes.iter().try_for_each(|node| fmt_foreign(node, f)),
+        node => write!(f, "{node}"),
+    }
+}
+
+impl GenericElement {
+    fn fmt_foreign(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let Self {
+            attributes,
+            children,
+            tag_name,
+        } = &self;
+        if children.is_empty() {
+            return write!(f, "<{tag_name}{attributes} />");
+        }
+        write!(f, "<{tag_name}{attributes}>")?;
+        for child in children {
+            if self.is_integration_point() {
+                write!(f, "{child}")?;
+            } else {
+                fmt_foreign(child, f)?;
+            }
+        }
+        write!(f, "</{tag_name}>")
+    }
+
+    /// Whether the children of a foreign element are HTML again.
+    fn is_integration_point(&self) -> bool {
+        match self.tag_name.as_str() {
+            "foreignObject" | "desc" | "title" | "mi" | "mo" | "mn" | "ms" | "mtext" => true,
+            "annotation-xml" => self.attributes.get("encoding").is_some_and(|encoding| {
+                encoding.eq_ignore_ascii_case("text/html")
+                    || encoding.eq_ignore_ascii_case("application/xhtml+xml")
+            }),
+            _ => false,
+        }
+    }
+
+    /// Appends a child, adding the nodes of a fragment instead of the fragment itself.
+    pub fn push_child(&mut self, child: Node) {
+        match child {
+            Node::Fragment(nodes) => nodes.into_iter().for_each(|node| self.push_child(node)),
+            child => self.children.push(child),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct VoidElement {
     pub tag_name: String,
@@ -78,10 +327,42 @@ pub trait Element: Sized {
 
     fn remove_attribute(self, key: impl Display) -> Self;
 
+    fn attributes(&self) -> &Attributes;
+
+    fn attributes_mut(&mut self) -> &mut Attributes;
+
+    /// Adds one or more space-separated classes, ignoring those already present.
     fn class(self, value: impl Display) -> Self {
         self.attribute("class", value)
     }
 
+    fn add_class(mut self, class: impl Display) -> Self {
+        self.attributes_mut().add_class(&class.to_string());
+        self
+    }
+
+    fn remove_class(mut self, class: impl Display) -> Self {
+        self.attributes_mut().remove_class(&class.to_string());
+        self
+    }
+
+    fn toggle_class(self, class: impl Display, enabled: bool) -> Self {
+        if enabled {
+            self.add_class(class)
+        } else {
+            self.remove_class(class)
+        }
+    }
+
+    fn has_class(&self, class: &str) -> bool {
+        self.attributes().has_class(class)
+    }
+
+    /// Sets inline styles, merging with any declarations already set.
+    fn css(self, style: impl FnOnce(InlineStyle) -> InlineStyle) -> Self {
+        self.attribute("style", style(InlineStyle::default()))
+    }
+
     fn hx_get(self, url: impl Display) -> Self {
         self.attribute("hx-get", url)
     }
@@ -99,8 +380,24 @@ pub trait Element: Sized {
         self.attribute("hx-swap", swap)
     }
 
-    fn hx_on(self, event: impl Display, script: impl Display) -> Self {
-        self.attribute(format!("hx-on:{event}"), script)
+    fn hx_on(self, event: impl HxEvent, script: impl Display) -> Self {
+        self.attribute(event.hx_on_attribute(), script)
+    }
+
+    /// Sets an attribute if `value` is `Some`.
+    fn maybe_attribute(self, key: impl Display, value: Option<impl Display>) -> Self {
+        match value {
+            Some(value) => self.attribute(key, value),
+            None => self,
+        }
+    }
+
+    fn attribute_if(self, condition: bool, key: impl Display, value: impl Display) -> Self {
+        if condition {
+            self.attribute(key, value)
+        } else {
+            self
+        }
     }
 
     // TODO: add docs?
@@ -108,14 +405,61 @@ pub trait Element: Sized {
         self.attribute("id", value)
     }
 
+    /// Sets a `data-*` attribute, with or without the `data-` prefix.
+    ///
+    /// Fails if the key is not a valid custom data attribute name, e.g. contains uppercase
+    /// letters, see [`is_valid_data_key`](crate::is_valid_data_key).
+    fn data(self, key: impl Display, value: impl Display) -> Result<Self, DataError> {
+        let key = key.to_string();
+        let key = key.strip_prefix("data-").unwrap_or(&key);
+        if !is_valid_data_key(key) {
+            return Err(DataError::InvalidKey(key.to_string()));
+        }
+        Ok(self.attribute(format!("data-{key}"), value))
+    }
+
+    /// Sets a `data-*` attribute to a value serialized as JSON.
+    fn data_json(
+        self,
+        key: impl Display,
+        value: &impl serde::Serialize,
+    ) -> Result<Self, DataError> {
+        let json = serde_json::to_string(value).map_err(DataError::Json)?;
+        self.data(key, json)
+    }
+
+    /// Sets an inline event handler, e.g. `on(Event::Click, script)` sets `onclick`.
+    fn on(self, event: Event, script: impl Display) -> Self {
+        self.attribute(format!("on{event}"), script)
+    }
+
+    fn role(self, role: Role) -> Self {
+        self.attribute("role", role)
+    }
+
+    aria_attributes!();
+
+    global_attributes!();
+
+    /// The element's tree, if it has one, which [`Element::response`] validates.
+    fn as_generic_element(&self) -> Option<&GenericElement> {
+        None
+    }
+
+    /// Renders a full document. In debug builds it is first validated according to the
+    /// [`Policy`](crate::validate::Policy) set with [`crate::validate::set_policy`].
     fn response(&self) -> hyper::Response<http_body_util::Full<hyper::body::Bytes>>
     where
         Self: Display,
     {
+        if let Some(element) = self.as_generic_element() {
+            crate::validate::debug_check(element);
+        }
+
         hyper::Response::builder()
             .header(hyper::header::CONTENT_TYPE, "text/html; charset=utf-8")
             .body(http_body_util::Full::new(hyper::body::Bytes::from(
-                format!("<!DOCTYPE html>\n{self}"),
+                format!("{}\n{self}", Node::doctype()),
             )))
             .unwrap()
     }
@@ -143,6 +487,40 @@ pub trait Children: Element {
         self
     }
 
+    /// Adds the child returned by `child` if `condition` is true, only building it if needed.
+    fn child_if<T>(self, condition: bool, child: impl FnOnce() -> T) -> Self
+    where
+        Node: From<T>,
+    {
+        if condition {
+            self.child(child())
+        } else {
+            self
+        }
+    }
+
+    fn child_opt<T>(self, child: Option<T>) -> Self
+    where
+        Node: From<T>,
+    {
+        match child {
+            Some(child) => self.child(child),
+            None => self,
+        }
+    }
+
+    /// Adds a child built from each item, e.g. `div().children_with(names, |name| p(name))`.
+    fn children_with<I, T>(
+        self,
+        items: impl IntoIterator<Item = I>,
+        child: impl FnMut(I) -> T,
+    ) -> Self
+    where
+        Node: From<T>,
+    {
+        self.children(items.into_iter().map(child))
+    }
+
     fn text(self, text: impl Display) -> Self {
         self.child(Node::Text(text.to_string()))
     }
@@ -153,5 +531,176 @@ pub trait Children: Element {
 }
 
 pub(crate) mod prelude {
-    pub use super::{Children, Element, GenericElement, Node, VoidElement};
+    pub use super::{Children, Element, GenericElement, Markup, Node, PreEscaped, VoidElement};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        prelude::{div, p, script, span, Children},
+        svg::{desc, g, svg, title},
+    };
+
+    #[test]
+    fn svg_integration_points_render_html() {
+        let image = svg()
+            .child(title().child(span()))
+            .child(desc().child(span()))
+            .child(g().child(g()));
+        assert!(image.to_string().ends_with(
+            "><title><span></span></title><desc><span></span></desc><g><g /></g></svg>"
+        ));
+    }
+
+    #[test]
+    fn comments_cannot_end_early() {
+        for text in [
+            "a-->b", "a--!>b", "a--b", "a---b", "a-", "a--", "->a", ">a", "-->", "-",
+        ] {
+            let html = Node::comment(text).to_string();
+            let content = &html["<!--".len()..html.len() - "-->".len()];
+            assert!(html.starts_with("<!--") && html.ends_with("-->"), "{html}");
+            assert!(!content.contains("--"), "{text:?} rendered as {html}");
+            assert!(
+                !content.starts_with('>') && !content.starts_with("->"),
+                "{html}"
+            );
+            assert!(!content.ends_with('-'), "{text:?} rendered as {html}");
+        }
+        assert_eq!(escape_comment("a-->b"), "a- ->b");
+        assert_eq!(escape_comment("a---b"), "a- - -b");
+        assert_eq!(escape_comment("a-"), "a- ");
+        assert_eq!(escape_comment(">a"), " >a");
+        assert_eq!(escape_comment("a <b> c"), "a <b> c");
+    }
+
+    #[test]
+    fn doctype() {
+        assert_eq!(Node::doctype().to_string(), "<!DOCTYPE html>");
+        assert_eq!(
+            Node::Doctype("html SYSTEM \"about:legacy-compat\"".to_string()).to_string(),
+            r#"<!DOCTYPE html SYSTEM "about:legacy-compat">"#
+        );
+    }
+
+    #[test]
+    fn conditional_comments() {
+        let html = Node::conditional_comment("lt IE 9", [script("html5shiv.js")]).to_string();
+        assert!(html.starts_with("<!--[if lt IE 9]><script"), "{html}");
+        assert!(html.ends_with("</script><![endif]-->"), "{html}");
+
+        let html =
+            Node::conditional_comment("IE]>--><script>x()</script><!--", [span()]).to_string();
+        assert_eq!(
+            html,
+            "<!--[if IE>- -><script>x()</script><!- - ]><span></span><![endif]-->"
+        );
+        assert_eq!(html.matches("-->").count(), 1);
+
+        let empty = Node::conditional_comment("IE", Vec::<Node>::new()).to_string();
+        assert_eq!(empty, "<!--[if IE]><![endif]-->");
+    }
+
+    #[test]
+    fn conditional_children() {
+        let built = &std::cell::Cell::new(0);
+        let build = |text: &'static str| {
+            move || {
+                built.set(built.get() + 1);
+                p(text)
+            }
+        };
+        let html = div()
+            .child_if(true, build("a"))
+            .child_if(false, build("b"))
+            .child_opt(Some(span()))
+            .child_opt(None::<Node>)
+            .children_with(["c", "d"], p)
+            .children_with(Vec::<&str>::new(), p)
+            .to_string();
+        assert_eq!(html, "<div><p>a</p><span></span><p>c</p><p>d</p></div>");
+        assert_eq!(built.get(), 1);
+    }
+
+    #[test]
+    fn conditional_attributes() {
+        let element = div()
+            .maybe_attribute("title", Some("a"))
+            .maybe_attribute("lang", None::<&str>)
+            .attribute_if(true, "hidden", "")
+            .attribute_if(false, "dir", "rtl");
+        let attributes = element.attributes();
+        assert_eq!(attributes.get("title"), Some("a"));
+        assert_eq!(attributes.get("hidden"), Some(""));
+        assert_eq!(attributes.get("lang"), None);
+        assert_eq!(attributes.get("dir"), None);
+    }
+
+    #[test]
+    fn option_and_vec_children() {
+        let html = div()
+            .child(Some(p("a")))
+            .child(None::<Node>)
+            .child(vec![span(), span()])
+            .child(Vec::<Node>::new())
+            .child(vec![Some("b"), None, Some("c")])
+            .to_string();
+        assert_eq!(html, "<div><p>a</p><span></span><span></span>bc</div>");
+        assert_eq!(Node::from(None::<&str>).to_string(), "");
+    }
+
+    #[test]
+    fn primitives_render_as_text() {
+        let owned = String::from("<b>");
+        let html = div()
+            .child("a&b")
+            .child(String::from("<i>"))
+            .child(&owned)
+            .child('"')
+            .child(true)
+            .child(-8i8)
+            .child(16u16)
+            .child(-32i32)
+            .child(64u64)
+            .child(i128::MIN)
+            .child(usize::MAX)
+            .child(0.1f32)
+            .child(1e21f64)
+            .child(-0.0f64)
+            .to_string();
+        assert_eq!(
+            html,
+            format!(
+                "<div>a&amp;b&lt;i&gt;&lt;b&gt;&quot;true-816-3264{}{}0.11000000000000000000000-0</div>",
+                i128::MIN,
+                usize::MAX,
+            )
+        );
+    }
+
+    #[test]
+    fn cow_renders_as_text() {
+        let borrowed = Node::from(Cow::Borrowed("<a>")).to_string();
+        let owned = Node::from(Cow::<str>::Owned("<a>".to_string())).to_string();
+        assert_eq!(borrowed, "&lt;a&gt;");
+        assert_eq!(owned, borrowed);
+    }
+
+    #[test]
+    fn pre_escaped_skips_escaping() {
+        let markup: Markup = PreEscaped("<em>b</em>".to_string());
+        let html = div()
+            .child("<em>a</em>")
+            .child(markup.clone())
+            .child(PreEscaped("<br>"))
+            .text("<c>")
+            .to_string();
+        assert_eq!(
+            html,
+            "<div>&lt;em&gt;a&lt;&#x2F;em&gt;<em>b</em><br>&lt;c&gt;</div>"
+        );
+        assert_eq!(markup.to_string(), "<em>b</em>");
+        assert!(matches!(Node::from(markup), Node::RawText(text) if text == "<em>b</em>"));
+    }
 }
@@ -0,0 +1,305 @@
+use crate::prelude::{style, Element, Style};
+use std::{cell::RefCell, fmt::Display};
+
+thread_local! {
+    static COLLECTOR: RefCell<Option<Vec<ScopedCss>>> = const { RefCell::new(None) };
+}
+
+/// CSS belonging to a single component, with every selector rewritten to only match inside
+/// the component's root element.
+///
+/// Selectors match descendants of the root element, and `:scope` refers to the root element
+/// itself, so `:scope > h2` becomes `.scope-class > h2` and `h2` becomes `.scope-class h2`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScopedCss {
+    scope: String,
+    css: String,
+}
+
+impl ScopedCss {
+    pub fn new(component: impl Display, css: impl Display) -> Self {
+        let (component, css) = (component.to_string(), css.to_string());
+        // Class names can't start with a digit, so the scope always starts with a letter.
+        let scope = format!(
+            "s-{}-{:08x}",
+            component.replace(
+                |c: char| !c.is_ascii_alphanumeric() && c != '-' && c != '_',
+                "-"
+            ),
+            crate::assets::hash(format!("{component}\0{css}").as_bytes()) as u32
+        );
+        let css = scope_block(&css, &format!(".{scope}"));
+        Self { scope, css }
+    }
+
+    /// The class added to the component's root element.
+    pub fn scope(&self) -> &str {
+        &self.scope
+    }
+
+    /// The rewritten CSS.
+    pub fn css(&self) -> &str {
+        &self.css
+    }
+
+    /// Adds the scope class to the component's root element and records the CSS with the
+    /// enclosing [`collect`] call.
+    pub fn apply<E: Element>(&self, root: E) -> E {
+        COLLECTOR.with_borrow_mut(|collector| {
+            if let Some(collected) = collector {
+                if !collected.iter().any(|css| css.scope == self.scope) {
+                    collected.push(self.clone());
+                }
+            }
+        });
+        root.class(&self.scope)
+    }
+}
+
+/// Renders a part of a document, collecting the CSS of every component applied while doing so
+/// into a single `<style>` element for the `<head>`, with each component included once.
+pub fn collect<T>(render: impl FnOnce() -> T) -> (T, Style) {
+    let previous = COLLECTOR.replace(Some(Vec::new()));
+    let rendered = render();
+    let collected = COLLECTOR.replace(previous).unwrap_or_default();
+
+    COLLECTOR.with_borrow_mut(|collector| {
+        if let Some(outer) = collector {
+            for css in &collected {
+                if !outer.iter().any(|outer| outer.scope == css.scope) {
+                    outer.push(css.clone());
+                }
+            }
+        }
+    });
+
+    let css = collected.iter().map(ScopedCss::css).collect::<String>();
+    (rendered, style(css))
+}
+
+fn scope_block(css: &str, scope: &str) -> String {
+    let mut output = String::new();
+    let mut rest = css;
+
+    loop {
+        rest = skip_comments(rest.trim_start());
+        if rest.is_empty() {
+            break;
+        }
+
+        let Some(end) = find_top_level(rest, |c| c == '{' || c == ';') else {
+            output.push_str(rest);
+            break;
+        };
+        let prelude = strip_comments(&rest[..end]);
+        let prelude = prelude.trim();
+
+        if rest[end..].starts_with(';') {
+            output.push_str(prelude);
+            output.push(';');
+            rest = &rest[end + 1..];
+            continue;
+        }
+
+        let body_start = end + 1;
+        let body_end = matching_brace(rest, end).unwrap_or(rest.len());
+        let body = &rest[body_start..body_end.min(rest.len())];
+
+        if let Some(at_rule) = prelude.strip_prefix('@') {
+            let name = at_rule
+                .split(|c: char| c.is_whitespace() || c == '(')
+                .next()
+                .unwrap_or_default();
+            let body = if matches!(
+                name,
+                "media" | "supports" | "container" | "layer" | "document" | "scope"
+            ) {
+                scope_block(body, scope)
+            } else {
+                body.to_string()
+            };
+            output.push_str(&format!("{prelude}{{{body}}}"));
+        } else {
+            output.push_str(&format!(
+                "{}{{{}}}",
+                scope_selectors(prelude, scope),
+                body.trim()
+            ));
+        }
+
+        rest = rest.get(body_end + 1..).unwrap_or_default();
+    }
+
+    output
+}
+
+fn scope_selectors(selectors: &str, scope: &str) -> String {
+    let mut scoped = Vec::new();
+    let mut rest = selectors;
+
+    loop {
+        let end = find_top_level(rest, |c| c == ',').unwrap_or(rest.len());
+        let selector = rest[..end].trim();
+        if !selector.is_empty() {
+            scoped.push(if selector.contains(":scope") {
+                selector.replace(":scope", scope)
+            } else {
+                format!("{scope} {selector}")
+            });
+        }
+
+        match rest.get(end + 1..) {
+            Some(remaining) => rest = remaining,
+            None => break,
+        }
+    }
+
+    scoped.join(",")
+}
+
+/// Finds the first character matching `predicate` outside of strings, comments, brackets and
+/// parentheses.
+fn find_top_level(css: &str, predicate: impl Fn(char) -> bool) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut quote = None;
+    let mut chars = css.char_indices();
+
+    while let Some((index, c)) = chars.next() {
+        match (quote, c) {
+            (Some(_), '\\') => {
+                chars.next();
+            }
+            (Some(open), c) if c == open => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '/') if css[index..].starts_with("/*") => {
+                chars.next();
+                let mut previous = None;
+                for (_, c) in chars.by_ref() {
+                    if previous == Some('*') && c == '/' {
+                        break;
+                    }
+                    previous = Some(c);
+                }
+            }
+            (None, '(' | '[') => depth += 1,
+            (None, ')' | ']') => depth = depth.saturating_sub(1),
+            (None, c) if depth == 0 && predicate(c) => return Some(index),
+            (None, _) => {}
+        }
+    }
+
+    None
+}
+
+fn matching_brace(css: &str, open: usize) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut rest = &css[open..];
+    let mut offset = open;
+
+    loop {
+        let index = find_top_level(rest, |c| c == '{' || c == '}')?;
+        if rest[index..].starts_with('{') {
+            depth += 1;
+        } else {
+            depth -= 1;
+            if depth == 0 {
+                return Some(offset + index);
+            }
+        }
+        offset += index + 1;
+        rest = &rest[index + 1..];
+    }
+}
+
+fn skip_comments(mut css: &str) -> &str {
+    while let Some(comment) = css.strip_prefix("/*") {
+        css = comment
+            .split_once("*/")
+            .map_or("", |(_, rest)| rest)
+            .trim_start();
+    }
+    css
+}
+
+fn strip_comments(css: &str) -> String {
+    let mut output = String::new();
+    let mut rest = css;
+    while let Some((before, comment)) = rest.split_once("/*") {
+        output.push_str(before);
+        rest = comment.split_once("*/").map_or("", |(_, rest)| rest);
+    }
+    output.push_str(rest);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scoped(css: &str) -> (String, String) {
+        let scoped = ScopedCss::new("card", css);
+        let scope = format!(".{}", scoped.scope());
+        (scoped.css().replace(&scope, ".x"), scope)
+    }
+
+    #[test]
+    fn scopes_selector_lists() {
+        assert_eq!(
+            scoped("h2, p > a:hover, :scope > footer { color: red }").0,
+            ".x h2,.x p > a:hover,.x > footer{color: red}"
+        );
+        assert_eq!(
+            scoped("a[title=\"a, b\"], :is(b, i) { color: red }").0,
+            ".x a[title=\"a, b\"],.x :is(b, i){color: red}"
+        );
+    }
+
+    #[test]
+    fn scopes_nested_at_rules() {
+        assert_eq!(
+            scoped(
+                "@media (min-width: 40em) { @supports (display: grid) { ul { display: grid } } }"
+            )
+            .0,
+            "@media (min-width: 40em){@supports (display: grid){.x ul{display: grid}}}"
+        );
+        assert_eq!(
+            scoped(
+                "@import url(\"a.css\"); @keyframes spin { from { rotate: 0 } } p { margin: 0 }"
+            )
+            .0,
+            "@import url(\"a.css\");@keyframes spin{ from { rotate: 0 } }.x p{margin: 0}"
+        );
+    }
+
+    #[test]
+    fn skips_comments() {
+        assert_eq!(
+            scoped("/* header } */ h1 /* { */ , h2 { color: red; /* } */ } p { margin: 0 }").0,
+            ".x h1,.x h2{color: red; /* } */}.x p{margin: 0}"
+        );
+    }
+
+    #[test]
+    fn ignores_braces_in_strings() {
+        assert_eq!(
+            scoped("q::before { content: \"}\" } a[title='{'] { content: \"\\\"{\" }").0,
+            ".x q::before{content: \"}\"}.x a[title='{']{content: \"\\\"{\"}"
+        );
+    }
+
+    #[test]
+    fn scope_starts_with_a_letter() {
+        let scoped = ScopedCss::new("1x grid", "p { margin: 0 }");
+        assert!(scoped.scope().starts_with("s-1x-grid-"));
+        assert_eq!(
+            scoped.scope(),
+            ScopedCss::new("1x grid", "p { margin: 0 }").scope()
+        );
+        assert_ne!(
+            scoped.scope(),
+            ScopedCss::new("1x grid", "p { margin: 1px }").scope()
+        );
+    }
+}
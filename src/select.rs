@@ -0,0 +1,926 @@
+use crate::{Attributes, GenericElement, Node, VoidElement};
+use std::{fmt::Display, str::FromStr};
+
+/// A parsed CSS selector list, e.g. `ul > li.active a[href], #main :nth-child(2n+1)`.
+///
+/// Supports type, universal, class, id and attribute selectors, the descendant, child,
+/// next-sibling and subsequent-sibling combinators, and the `:nth-child`, `:nth-last-child`,
+/// `:first-child`, `:last-child`, `:only-child`, `:empty` and `:not` pseudo-classes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selector(Vec<Complex>);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectorError(String);
+
+impl Display for SelectorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "invalid selector: {}", self.0)
+    }
+}
+
+impl std::error::Error for SelectorError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Complex {
+    compounds: Vec<Compound>,
+    /// `combinators[i]` joins `compounds[i]` and `compounds[i + 1]`.
+    combinators: Vec<Combinator>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Combinator {
+    Descendant,
+    Child,
+    NextSibling,
+    SubsequentSibling,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+struct Compound {
+    tag_name: Option<String>,
+    conditions: Vec<Condition>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Condition {
+    Id(String),
+    Class(String),
+    Attribute(String, Option<(AttributeOperator, String)>),
+    NthChild { a: i32, b: i32, from_end: bool },
+    Empty,
+    Not(Selector),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AttributeOperator {
+    Equals,
+    Includes,
+    DashMatch,
+    Prefix,
+    Suffix,
+    Substring,
+}
+
+impl FromStr for Selector {
+    type Err = SelectorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser {
+            chars: s.chars().collect(),
+            position: 0,
+        };
+        let selector = parser.selector_list()?;
+        parser.skip_whitespace();
+        match parser.peek() {
+            None => Ok(selector),
+            Some(c) => Err(parser.error(format!("unexpected {c:?}"))),
+        }
+    }
+}
+
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+}
+
+impl Parser {
+    fn error(&self, message: impl Display) -> SelectorError {
+        SelectorError(format!("{message} at {}", self.position))
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn skip_whitespace(&mut self) -> bool {
+        let start = self.position;
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.position += 1;
+        }
+        self.position > start
+    }
+
+    fn identifier(&mut self) -> Result<String, SelectorError> {
+        let mut identifier = String::new();
+        while let Some(c) = self.peek() {
+            if c == '\\' {
+                self.position += 1;
+                identifier.extend(self.peek());
+            } else if c.is_alphanumeric() || c == '-' || c == '_' || !c.is_ascii() {
+                identifier.push(c);
+            } else {
+                break;
+            }
+            self.position += 1;
+        }
+
+        if identifier.is_empty() {
+            Err(self.error("expected an identifier"))
+        } else {
+            Ok(identifier)
+        }
+    }
+
+    fn selector_list(&mut self) -> Result<Selector, SelectorError> {
+        let mut complexes = vec![self.complex()?];
+        loop {
+            self.skip_whitespace();
+            if !self.eat(',') {
+                break;
+            }
+            complexes.push(self.complex()?);
+        }
+        Ok(Selector(complexes))
+    }
+
+    fn complex(&mut self) -> Result<Complex, SelectorError> {
+        self.skip_whitespace();
+        let mut complex = Complex {
+            compounds: vec![self.compound()?],
+            combinators: Vec::new(),
+        };
+
+        loop {
+            let whitespace = self.skip_whitespace();
+            let combinator = match self.peek() {
+                Some('>') => Combinator::Child,
+                Some('+') => Combinator::NextSibling,
+                Some('~') => Combinator::SubsequentSibling,
+                Some(',' | ')') | None => break,
+                Some(_) if whitespace => Combinator::Descendant,
+                Some(c) => return Err(self.error(format!("unexpected {c:?}"))),
+            };
+            if combinator != Combinator::Descendant {
+                self.position += 1;
+                self.skip_whitespace();
+            }
+            complex.combinators.push(combinator);
+            complex.compounds.push(self.compound()?);
+        }
+
+        Ok(complex)
+    }
+
+    fn compound(&mut self) -> Result<Compound, SelectorError> {
+        let mut compound = Compound::default();
+        let universal = self.eat('*');
+        if !universal
+            && self
+                .peek()
+                .is_some_and(|c| c.is_alphabetic() || c == '\\' || !c.is_ascii())
+        {
+            compound.tag_name = Some(self.identifier()?);
+        }
+        let start = self.position;
+
+        loop {
+            let condition = match self.peek() {
+                Some('#') => {
+                    self.position += 1;
+                    Condition::Id(self.identifier()?)
+                }
+                Some('.') => {
+                    self.position += 1;
+                    Condition::Class(self.identifier()?)
+                }
+                Some('[') => {
+                    self.position += 1;
+                    self.attribute()?
+                }
+                Some(':') => {
+                    self.position += 1;
+                    self.pseudo_class()?
+                }
+                _ => break,
+            };
+            compound.conditions.push(condition);
+        }
+
+        if !universal && compound.tag_name.is_none() && self.position == start {
+            return Err(self.error("expected a selector"));
+        }
+        Ok(compound)
+    }
+
+    fn attribute(&mut self) -> Result<Condition, SelectorError> {
+        self.skip_whitespace();
+        let name = self.identifier()?;
+        self.skip_whitespace();
+
+        let operator = match self.peek() {
+            Some(']') => {
+                self.position += 1;
+                return Ok(Condition::Attribute(name, None));
+            }
+            Some('=') => AttributeOperator::Equals,
+            Some(c) => {
+                self.position += 1;
+                match c {
+                    '~' => AttributeOperator::Includes,
+                    '|' => AttributeOperator::DashMatch,
+                    '^' => AttributeOperator::Prefix,
+                    '$' => AttributeOperator::Suffix,
+                    '*' => AttributeOperator::Substring,
+                    c => return Err(self.error(format!("unexpected {c:?}"))),
+                }
+            }
+            None => return Err(self.error("unterminated attribute selector")),
+        };
+        if !self.eat('=') {
+            return Err(self.error("expected '='"));
+        }
+        self.skip_whitespace();
+
+        let value = match self.peek() {
+            Some(quote @ ('"' | '\'')) => {
+                self.position += 1;
+                let mut value = String::new();
+                loop {
+                    match self.peek() {
+                        None => return Err(self.error("unterminated string")),
+                        Some('\\') => {
+                            self.position += 1;
+                            value.extend(self.peek());
+                        }
+                        Some(c) if c == quote => break,
+                        Some(c) => value.push(c),
+                    }
+                    self.position += 1;
+                }
+                self.position += 1;
+                value
+            }
+            _ => self.identifier()?,
+        };
+
+        self.skip_whitespace();
+        if !self.eat(']') {
+            return Err(self.error("expected ']'"));
+        }
+        Ok(Condition::Attribute(name, Some((operator, value))))
+    }
+
+    fn pseudo_class(&mut self) -> Result<Condition, SelectorError> {
+        let name = self.identifier()?.to_ascii_lowercase();
+        let condition = match name.as_str() {
+            "first-child" => Condition::NthChild {
+                a: 0,
+                b: 1,
+                from_end: false,
+            },
+            "last-child" => Condition::NthChild {
+                a: 0,
+                b: 1,
+                from_end: true,
+            },
+            "only-child" => Condition::Not(
+                ":not(:first-child), :not(:last-child)"
+                    .parse()
+                    .expect("valid selector"),
+            ),
+            "empty" => Condition::Empty,
+            "nth-child" | "nth-last-child" => {
+                let (a, b) = self.arguments(Self::nth)?;
+                Condition::NthChild {
+                    a,
+                    b,
+                    from_end: name == "nth-last-child",
+                }
+            }
+            "not" => Condition::Not(self.arguments(Self::selector_list)?),
+            _ => return Err(self.error(format!("unsupported pseudo-class :{name}"))),
+        };
+        Ok(condition)
+    }
+
+    fn arguments<T>(
+        &mut self,
+        parse: impl FnOnce(&mut Self) -> Result<T, SelectorError>,
+    ) -> Result<T, SelectorError> {
+        if !self.eat('(') {
+            return Err(self.error("expected '('"));
+        }
+        self.skip_whitespace();
+        let value = parse(self)?;
+        self.skip_whitespace();
+        if !self.eat(')') {
+            return Err(self.error("expected ')'"));
+        }
+        Ok(value)
+    }
+
+    /// Parses the `an+b` syntax.
+    fn nth(&mut self) -> Result<(i32, i32), SelectorError> {
+        let start = self.position;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_alphanumeric() || matches!(c, '+' | '-' | ' '))
+        {
+            self.position += 1;
+        }
+        let expression = self.chars[start..self.position]
+            .iter()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>()
+            .to_ascii_lowercase();
+
+        let invalid = || SelectorError(format!("invalid nth expression {expression:?}"));
+        match expression.as_str() {
+            "odd" => Ok((2, 1)),
+            "even" => Ok((2, 0)),
+            _ => match expression.split_once('n') {
+                None => Ok((0, expression.parse().map_err(|_| invalid())?)),
+                Some((a, b)) => {
+                    let a = match a {
+                        "" | "+" => 1,
+                        "-" => -1,
+                        a => a.parse().map_err(|_| invalid())?,
+                    };
+                    let b = match b {
+                        "" => 0,
+                        b => b
+                            .strip_prefix('+')
+                            .unwrap_or(b)
+                            .parse()
+                            .map_err(|_| invalid())?,
+                    };
+                    Ok((a, b))
+                }
+            },
+        }
+    }
+}
+
+/// An element being matched, along with its parent and siblings.
+struct Position<'a, 'p> {
+    tag_name: &'a str,
+    attributes: &'a Attributes,
+    children: &'a [Node],
+    parent: Option<&'p Position<'a, 'p>>,
+    siblings: &'a [Node],
+    index: usize,
+}
+
+impl<'a, 'p> Position<'a, 'p> {
+    fn sibling(&self, index: usize) -> Option<Position<'a, 'p>> {
+        let node = &self.siblings[index];
+        Some(Position {
+            tag_name: node.tag_name()?,
+            attributes: node.attributes()?,
+            children: node.children(),
+            parent: self.parent,
+            siblings: self.siblings,
+            index,
+        })
+    }
+
+    /// Previous element siblings, closest first.
+    fn previous_siblings(&self) -> impl Iterator<Item = Position<'a, 'p>> + '_ {
+        (0..self.index)
+            .rev()
+            .filter_map(|index| self.sibling(index))
+    }
+}
+
+impl Selector {
+    fn matches_position(&self, position: &Position) -> bool {
+        self.0
+            .iter()
+            .any(|complex| complex.matches(complex.compounds.len() - 1, position))
+    }
+
+    /// Whether a node matches, without taking its ancestors or siblings into account.
+    pub fn matches(&self, node: &Node) -> bool {
+        let (Some(tag_name), Some(attributes)) = (node.tag_name(), node.attributes()) else {
+            return false;
+        };
+        self.matches_position(&Position {
+            tag_name,
+            attributes,
+            children: node.children(),
+            parent: None,
+            siblings: &[],
+            index: 0,
+        })
+    }
+}
+
+impl Complex {
+    fn matches(&self, index: usize, position: &Position) -> bool {
+        if !self.compounds[index].matches(position) {
+            return false;
+        }
+        let Some(previous) = index.checked_sub(1) else {
+            return true;
+        };
+
+        match self.combinators[previous] {
+            Combinator::Child => position
+                .parent
+                .is_some_and(|parent| self.matches(previous, parent)),
+            Combinator::Descendant => {
+                let mut ancestor = position.parent;
+                while let Some(parent) = ancestor {
+                    if self.matches(previous, parent) {
+                        return true;
+                    }
+                    ancestor = parent.parent;
+                }
+                false
+            }
+            Combinator::NextSibling => position
+                .previous_siblings()
+                .next()
+                .is_some_and(|sibling| self.matches(previous, &sibling)),
+            Combinator::SubsequentSibling => position
+                .previous_siblings()
+                .any(|sibling| self.matches(previous, &sibling)),
+        }
+    }
+}
+
+impl Compound {
+    fn matches(&self, position: &Position) -> bool {
+        self.tag_name
+            .as_ref()
+            .is_none_or(|tag_name| name_matches(tag_name, position.tag_name))
+            && self
+                .conditions
+                .iter()
+                .all(|condition| condition.matches(position))
+    }
+}
+
+/// Tag and attribute names of HTML elements are matched case-insensitively. The builder writes
+/// them in lowercase, so a name with uppercase letters belongs to SVG or MathML, such as
+/// `linearGradient` or `viewBox`, and is matched exactly.
+fn name_matches(selector: &str, name: &str) -> bool {
+    name == selector
+        || (!name.bytes().any(|b| b.is_ascii_uppercase()) && name.eq_ignore_ascii_case(selector))
+}
+
+fn attribute<'a>(attributes: &'a Attributes, name: &str) -> Option<&'a str> {
+    attributes.get(name).or_else(|| {
+        attributes
+            .0
+            .iter()
+            .find(|(key, _)| name_matches(name, key))
+            .map(|(_, value)| value.as_str())
+    })
+}
+
+impl Condition {
+    fn matches(&self, position: &Position) -> bool {
+        let attributes = position.attributes;
+        match self {
+            Self::Id(id) => attributes.get("id") == Some(id.as_str()),
+            Self::Class(class) => attributes.has_class(class),
+            Self::Attribute(name, None) => attribute(attributes, name).is_some(),
+            Self::Attribute(name, Some((operator, expected))) => {
+                let Some(value) = attribute(attributes, name) else {
+                    return false;
+                };
+                match operator {
+                    AttributeOperator::Equals => value == expected,
+                    AttributeOperator::Includes => {
+                        value.split_ascii_whitespace().any(|word| word == expected)
+                    }
+                    AttributeOperator::DashMatch => {
+                        value == expected || value.starts_with(&format!("{expected}-"))
+                    }
+                    AttributeOperator::Prefix => {
+                        !expected.is_empty() && value.starts_with(expected)
+                    }
+                    AttributeOperator::Suffix => !expected.is_empty() && value.ends_with(expected),
+                    AttributeOperator::Substring => {
+                        !expected.is_empty() && value.contains(expected.as_str())
+                    }
+                }
+            }
+            Self::NthChild { a, b, from_end } => {
+                if position.siblings.is_empty() {
+                    return false;
+                }
+                let elements = |range: &[Node]| {
+                    range
+                        .iter()
+                        .filter(|node| node.tag_name().is_some())
+                        .count() as i32
+                };
+                let n = if *from_end {
+                    elements(&position.siblings[position.index..])
+                } else {
+                    elements(&position.siblings[..=position.index])
+                };
+                match a {
+                    0 => n == *b,
+                    a => (n - b) % a == 0 && (n - b) / a >= 0,
+                }
+            }
+            Self::Empty => position.children.iter().all(|child| match child {
+                Node::Text(text) | Node::RawText(text) => text.is_empty(),
+                Node::Comment(_) => true,
+                _ => false,
+            }),
+            Self::Not(selector) => !selector.matches_position(position),
+        }
+    }
+}
+
+fn parse(selector: &str) -> Selector {
+    selector
+        .parse()
+        .unwrap_or_else(|error| panic!("{error}: {selector:?}"))
+}
+
+/// Finds the paths of descendants of `parent` matching `selector`, in document order.
+fn matching_paths(
+    selector: &Selector,
+    parent: &Position,
+    prefix: &mut Vec<usize>,
+    paths: &mut Vec<Vec<usize>>,
+) {
+    for (index, child) in parent.children.iter().enumerate() {
+        let (Some(tag_name), Some(attributes)) = (child.tag_name(), child.attributes()) else {
+            // A fragment's children are matched as if they were children of `parent`.
+            if let Node::Fragment(nodes) = child {
+                let position = Position {
+                    children: nodes,
+                    ..*parent
+                };
+                prefix.push(index);
+                matching_paths(selector, &position, prefix, paths);
+                prefix.pop();
+            }
+            continue;
+        };
+        let position = Position {
+            tag_name,
+            attributes,
+            children: child.children(),
+            parent: Some(parent),
+            siblings: parent.children,
+            index,
+        };
+
+        prefix.push(index);
+        if selector.matches_position(&position) {
+            paths.push(prefix.clone());
+        }
+        matching_paths(selector, &position, prefix, paths);
+        prefix.pop();
+    }
+}
+
+fn node_at<'a>(children: &'a [Node], path: &[usize]) -> &'a Node {
+    let node = &children[path[0]];
+    match path {
+        [_] => node,
+        [_, rest @ ..] => node_at(node.children(), rest),
+        [] => unreachable!(),
+    }
+}
+
+/// Collects mutable references to the nodes at `paths`, which must be sorted and disjoint.
+fn nodes_at_mut<'a>(children: &'a mut [Node], paths: &[&[usize]], nodes: &mut Vec<&'a mut Node>) {
+    let mut paths = paths.iter().peekable();
+    for (index, child) in children.iter_mut().enumerate() {
+        let mut descendants = Vec::new();
+        let mut matched = false;
+        while let Some(path) = paths.next_if(|path| path[0] == index) {
+            match &path[1..] {
+                [] => matched = true,
+                rest => descendants.push(rest),
+            }
+        }
+
+        if matched {
+            nodes.push(child);
+        } else if !descendants.is_empty() {
+            match child {
+                Node::Element(element) => {
+                    nodes_at_mut(&mut element.children, &descendants, nodes);
+                }
+                Node::Fragment(children) => nodes_at_mut(children, &descendants, nodes),
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Pre-order iterator over the descendants of a node, see [`Node::descendants`].
+pub struct Descendants<'a> {
+    stack: Vec<&'a Node>,
+}
+
+impl<'a> Descendants<'a> {
+    fn new(children: &'a [Node]) -> Self {
+        Self {
+            stack: children.iter().rev().collect(),
+        }
+    }
+}
+
+impl<'a> Iterator for Descendants<'a> {
+    type Item = &'a Node;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.stack.extend(node.children().iter().rev());
+        Some(node)
+    }
+}
+
+macro_rules! query_methods {
+    ($type:ty, |$self:ident| $root:expr, |$self_mut:ident| $children_mut:expr) => {
+        impl $type {
+            /// Iterates over every descendant node in document order.
+            pub fn descendants(&self) -> Descendants<'_> {
+                Descendants::new(self.query_root().children)
+            }
+
+            /// Finds the first descendant element with the given `id`.
+            pub fn find_by_id(&self, id: &str) -> Option<&Node> {
+                self.descendants().find(|node| {
+                    node.attributes()
+                        .is_some_and(|attributes| attributes.get("id") == Some(id))
+                })
+            }
+
+            /// Finds every descendant element matching a CSS selector, like `querySelectorAll`.
+            ///
+            /// # Panics
+            /// If the selector is invalid, use [`Self::select_parsed`] to handle errors.
+            pub fn select(&self, selector: &str) -> Vec<&Node> {
+                self.select_parsed(&parse(selector))
+            }
+
+            pub fn select_parsed(&self, selector: &Selector) -> Vec<&Node> {
+                let root = self.query_root();
+                let mut paths = Vec::new();
+                matching_paths(selector, &root, &mut Vec::new(), &mut paths);
+                paths
+                    .iter()
+                    .map(|path| node_at(root.children, path))
+                    .collect()
+            }
+
+            /// Like [`Self::select`], but only returns the outermost of nested matches so that
+            /// the references do not overlap.
+            ///
+            /// # Panics
+            /// If the selector is invalid.
+            pub fn select_mut(&mut self, selector: &str) -> Vec<&mut Node> {
+                let selector = parse(selector);
+                let mut paths = Vec::new();
+                matching_paths(&selector, &self.query_root(), &mut Vec::new(), &mut paths);
+
+                let mut outermost = Vec::<&[usize]>::new();
+                for path in &paths {
+                    if !outermost.iter().any(|outer| path.starts_with(outer)) {
+                        outermost.push(path);
+                    }
+                }
+
+                let mut nodes = Vec::new();
+                let $self_mut = self;
+                if let Some(children) = $children_mut {
+                    nodes_at_mut(children, &outermost, &mut nodes);
+                }
+                nodes
+            }
+
+            fn query_root(&self) -> Position<'_, '_> {
+                let $self = self;
+                $root
+            }
+        }
+    };
+}
+
+static NO_ATTRIBUTES: std::sync::OnceLock<Attributes> = std::sync::OnceLock::new();
+
+query_methods!(
+    Node,
+    |node| Position {
+        tag_name: node.tag_name().unwrap_or_default(),
+        attributes: node
+            .attributes()
+            .unwrap_or_else(|| NO_ATTRIBUTES.get_or_init(Attributes::default)),
+        children: node.children(),
+        parent: None,
+        siblings: &[],
+        index: 0,
+    },
+    |node| match node {
+        Node::Element(element) => Some(&mut element.children),
+        Node::Fragment(nodes) => Some(nodes),
+        _ => None,
+    }
+);
+
+query_methods!(
+    GenericElement,
+    |element| Position {
+        tag_name: &element.tag_name,
+        attributes: &element.attributes,
+        children: &element.children,
+        parent: None,
+        siblings: &[],
+        index: 0,
+    },
+    |element| Some(&mut element.children)
+);
+
+query_methods!(
+    VoidElement,
+    |element| Position {
+        tag_name: &element.tag_name,
+        attributes: &element.attributes,
+        children: &[],
+        parent: None,
+        siblings: &[],
+        index: 0,
+    },
+    |_element| None::<&mut Vec<Node>>
+);
+
+impl Node {
+    /// Whether this node matches a CSS selector, ignoring combinators that need its ancestors
+    /// or siblings.
+    ///
+    /// # Panics
+    /// If the selector is invalid.
+    pub fn matches(&self, selector: &str) -> bool {
+        parse(selector).matches(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        prelude::{div, p, span, Children, Element, Li, Ul},
+        svg::{linear_gradient, svg},
+    };
+
+    fn ids(node: impl Into<Node>, selector: &str) -> Vec<String> {
+        node.into()
+            .select(selector)
+            .into_iter()
+            .filter_map(|node| Some(node.attributes()?.get("id")?.to_string()))
+            .collect()
+    }
+
+    fn ul() -> Ul {
+        Ul::new_empty()
+    }
+
+    fn li() -> Li {
+        Li::new_empty()
+    }
+
+    fn list(items: usize) -> Ul {
+        (1..=items).fold(ul(), |list, index| list.child(li().id(index)))
+    }
+
+    #[test]
+    fn combinators() {
+        let page = div().id("root").child(
+            div()
+                .id("a")
+                .child(p("").id("b").child(span().id("c")))
+                .child(span().id("d"))
+                .child(p("").id("e"))
+                .child(span().id("f")),
+        );
+        assert_eq!(ids(page.clone(), "div span"), ["c", "d", "f"]);
+        assert_eq!(ids(page.clone(), "div > span"), ["d", "f"]);
+        assert_eq!(ids(page.clone(), "#root > div > span"), ["d", "f"]);
+        assert_eq!(ids(page.clone(), "p + span"), ["d", "f"]);
+        assert_eq!(ids(page.clone(), "#b ~ p"), ["e"]);
+        assert_eq!(ids(page.clone(), "#b ~ *"), ["d", "e", "f"]);
+        assert_eq!(ids(page.clone(), "#e + span, p > span"), ["c", "f"]);
+        assert_eq!(ids(page, "#root>div>p>span"), ["c"]);
+    }
+
+    #[test]
+    fn nth_child() {
+        assert_eq!(ids(list(7), "li:nth-child(2n+1)"), ["1", "3", "5", "7"]);
+        assert_eq!(ids(list(7), "li:nth-child( odd )"), ["1", "3", "5", "7"]);
+        assert_eq!(ids(list(7), "li:nth-child(EVEN)"), ["2", "4", "6"]);
+        assert_eq!(ids(list(7), "li:nth-child(3n)"), ["3", "6"]);
+        assert_eq!(ids(list(7), "li:nth-child(-n + 3)"), ["1", "2", "3"]);
+        assert_eq!(ids(list(7), "li:nth-child(n+5)"), ["5", "6", "7"]);
+        assert_eq!(ids(list(7), "li:nth-child(4)"), ["4"]);
+        assert_eq!(ids(list(7), "li:nth-last-child(2n)"), ["2", "4", "6"]);
+        assert_eq!(ids(list(7), "li:first-child, li:last-child"), ["1", "7"]);
+        assert_eq!(ids(list(1), "li:only-child"), ["1"]);
+        assert!(ids(list(2), "li:only-child").is_empty());
+
+        let text_between = ul().child(li().id(1)).child("text").child(li().id(2));
+        assert_eq!(ids(text_between, "li:nth-child(2)"), ["2"]);
+    }
+
+    #[test]
+    fn not() {
+        assert_eq!(ids(list(4), "li:not(:first-child)"), ["2", "3", "4"]);
+        assert_eq!(ids(list(4), "li:not(#2, #3)"), ["1", "4"]);
+        assert_eq!(ids(list(4), "li:not(:not(#2))"), ["2"]);
+        assert_eq!(ids(list(2), ":not(li)"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn attribute_operators() {
+        let link = |id: &str, value: &str| li().id(id).attribute("data-x", value);
+        let page = ul()
+            .child(link("a", "en-US"))
+            .child(link("b", "one two"))
+            .child(link("c", "prefix-suffix"))
+            .child(link("d", ""))
+            .child(li().id("e"));
+        assert_eq!(ids(page.clone(), "[data-x]"), ["a", "b", "c", "d"]);
+        assert_eq!(ids(page.clone(), "[data-x=\"en-US\"]"), ["a"]);
+        assert_eq!(ids(page.clone(), "[data-x~=two]"), ["b"]);
+        assert_eq!(ids(page.clone(), "[data-x|=en]"), ["a"]);
+        assert_eq!(ids(page.clone(), "[data-x^=pre]"), ["c"]);
+        assert_eq!(ids(page.clone(), "[data-x$='fix']"), ["c"]);
+        assert_eq!(ids(page.clone(), "[data-x*=e]"), ["a", "b", "c"]);
+        assert_eq!(ids(page.clone(), "[data-x^=\"\"]"), Vec::<String>::new());
+        assert_eq!(ids(page.clone(), "[DATA-X=\"\"]"), ["d"]);
+        assert_eq!(ids(page, "LI[ data-x = 'one two' ]"), ["b"]);
+    }
+
+    #[test]
+    fn foreign_names_are_case_sensitive() {
+        let image = div().child(
+            svg()
+                .view_box("0 0 1 1")
+                .child(linear_gradient().id("gradient")),
+        );
+        assert_eq!(ids(image.clone(), "linearGradient"), ["gradient"]);
+        assert!(ids(image.clone(), "lineargradient").is_empty());
+        assert_eq!(Node::from(image.clone()).select("[viewBox]").len(), 1);
+        assert!(Node::from(image.clone()).select("[viewbox]").is_empty());
+        assert_eq!(Node::from(image).select("SVG").len(), 1);
+    }
+
+    #[test]
+    fn parse_errors() {
+        for selector in [
+            "",
+            "div >",
+            "> div",
+            "div,",
+            ".",
+            "#",
+            "[href",
+            "[href=]",
+            "[href!=a]",
+            "[href='a]",
+            ":hover",
+            ":nth-child(x)",
+            ":nth-child(2n+)",
+            ":not(div",
+            "div )",
+            "a!b",
+        ] {
+            assert!(
+                selector.parse::<Selector>().is_err(),
+                "{selector:?} should be invalid"
+            );
+        }
+        assert_eq!(
+            "div >".parse::<Selector>().unwrap_err().to_string(),
+            "invalid selector: expected a selector at 5"
+        );
+    }
+
+    #[test]
+    fn selects_through_fragments() {
+        let mut fragment = Node::Fragment(vec![
+            p("").class("a").into(),
+            div()
+                .child(Node::Fragment(vec![span().class("a").into()]))
+                .into(),
+        ]);
+        assert_eq!(fragment.select(".a").len(), 2);
+        assert_eq!(fragment.select("div > span").len(), 1);
+
+        for node in fragment.select_mut(".a") {
+            if let Node::Element(element) = node {
+                element.attributes.set("title".to_string(), "x".to_string());
+            }
+        }
+        assert_eq!(fragment.select("[title=x]").len(), 2);
+    }
+}
@@ -0,0 +1,387 @@
+use crate::macros::keywords;
+use std::fmt;
+
+/// The declarations of an inline `style` attribute, see [`Element::css`](crate::Element::css).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InlineStyle(Vec<(String, String)>);
+
+macro_rules! properties {
+    ($($method:ident => $name:literal: $value:ty;)*) => {
+        $(
+            #[doc = concat!("Sets the `", $name, "` property.")]
+            pub fn $method(self, value: $value) -> Self {
+                self.property($name, value)
+            }
+        )*
+    };
+}
+
+impl InlineStyle {
+    /// Sets a property, replacing any previous value.
+    pub fn property(mut self, name: impl fmt::Display, value: impl fmt::Display) -> Self {
+        let name = escape_name(&name.to_string());
+        let value = escape_value(&value.to_string());
+        match self.0.iter_mut().find(|(existing, _)| *existing == name) {
+            Some((_, existing)) => *existing = value,
+            None => self.0.push((name, value)),
+        }
+        self
+    }
+
+    /// Sets a custom property, adding the `--` prefix if it is missing.
+    pub fn custom(self, name: impl fmt::Display, value: impl fmt::Display) -> Self {
+        let name = name.to_string();
+        if name.starts_with("--") {
+            self.property(name, value)
+        } else {
+            self.property(format!("--{name}"), value)
+        }
+    }
+
+    properties! {
+        display => "display": Display;
+        position => "position": Position;
+        top => "top": impl fmt::Display;
+        right => "right": impl fmt::Display;
+        bottom => "bottom": impl fmt::Display;
+        left => "left": impl fmt::Display;
+        inset => "inset": impl fmt::Display;
+        z_index => "z-index": i32;
+        width => "width": impl fmt::Display;
+        height => "height": impl fmt::Display;
+        min_width => "min-width": impl fmt::Display;
+        min_height => "min-height": impl fmt::Display;
+        max_width => "max-width": impl fmt::Display;
+        max_height => "max-height": impl fmt::Display;
+        margin => "margin": impl fmt::Display;
+        padding => "padding": impl fmt::Display;
+        gap => "gap": impl fmt::Display;
+        flex => "flex": impl fmt::Display;
+        flex_direction => "flex-direction": FlexDirection;
+        flex_wrap => "flex-wrap": FlexWrap;
+        align_items => "align-items": Align;
+        align_self => "align-self": Align;
+        justify_content => "justify-content": Align;
+        grid_template_columns => "grid-template-columns": impl fmt::Display;
+        grid_template_rows => "grid-template-rows": impl fmt::Display;
+        grid_column => "grid-column": impl fmt::Display;
+        grid_row => "grid-row": impl fmt::Display;
+        color => "color": impl fmt::Display;
+        background => "background": impl fmt::Display;
+        background_color => "background-color": impl fmt::Display;
+        border => "border": impl fmt::Display;
+        border_radius => "border-radius": impl fmt::Display;
+        opacity => "opacity": f32;
+        overflow => "overflow": Overflow;
+        font_family => "font-family": impl fmt::Display;
+        font_size => "font-size": impl fmt::Display;
+        font_weight => "font-weight": impl fmt::Display;
+        line_height => "line-height": impl fmt::Display;
+        text_align => "text-align": TextAlign;
+        cursor => "cursor": impl fmt::Display;
+        transform => "transform": impl fmt::Display;
+        transition => "transition": impl fmt::Display;
+        visibility => "visibility": Visibility;
+    }
+}
+
+impl fmt::Display for InlineStyle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (index, (name, value)) in self.0.iter().enumerate() {
+            if index > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{name}: {value};")?;
+        }
+        Ok(())
+    }
+}
+
+/// Merges two `style` attribute values, with declarations in `new` overriding those in `existing`.
+pub(crate) fn merge(existing: &str, new: &str) -> String {
+    let mut style = InlineStyle::default();
+    for declaration in declarations(existing).chain(declarations(new)) {
+        let Some((name, value)) = declaration.split_once(':') else {
+            continue;
+        };
+        let name = name.trim().to_string();
+        let value = value.trim().to_string();
+        match style.0.iter_mut().find(|(existing, _)| *existing == name) {
+            Some((_, existing)) => *existing = value,
+            None => style.0.push((name, value)),
+        }
+    }
+    style.to_string()
+}
+
+/// Splits on `;` outside of strings and parentheses.
+fn declarations(style: &str) -> impl Iterator<Item = &str> {
+    let mut declarations = Vec::new();
+    let (mut start, mut depth, mut quote, mut escaped) = (0, 0usize, None, false);
+
+    for (index, c) in style.char_indices() {
+        match (quote, c) {
+            _ if escaped => escaped = false,
+            (_, '\\') => escaped = true,
+            (Some(open), c) if c == open => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '(') => depth += 1,
+            (None, ')') => depth = depth.saturating_sub(1),
+            (None, ';') if depth == 0 => {
+                declarations.push(&style[start..index]);
+                start = index + 1;
+            }
+            (None, _) => {}
+        }
+    }
+    declarations.push(&style[start..]);
+
+    declarations
+        .into_iter()
+        .filter(|declaration| !declaration.trim().is_empty())
+}
+
+fn escape_name(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' || !c.is_ascii() {
+                c.to_string()
+            } else {
+                format!("\\{c}")
+            }
+        })
+        .collect()
+}
+
+/// Escapes what would let a value end its declaration or the `style` attribute: `;`, braces
+/// outside of strings, `<`, an unterminated string and a trailing backslash. CSS escapes such
+/// as `"\201C"` are kept as they are.
+fn escape_value(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    let (mut quote, mut chars) = (None, value.chars().peekable());
+
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            (_, '\\') => match chars.peek() {
+                None => escaped.push_str("\\\\"),
+                Some('<' | '\n' | '\r' | '\0') => {}
+                Some(_) => {
+                    escaped.push('\\');
+                    escaped.extend(chars.next());
+                }
+            },
+            (_, '<') => escaped.push_str("\\3c "),
+            (_, '\n') => escaped.push_str("\\a "),
+            (_, '\r' | '\0') => {}
+            (Some(open), c) if c == open => {
+                quote = None;
+                escaped.push(c);
+            }
+            (None, '"' | '\'') => {
+                quote = Some(c);
+                escaped.push(c);
+            }
+            (None, ';' | '{' | '}') => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            (_, c) => escaped.push(c),
+        }
+    }
+    if let Some(open) = quote {
+        escaped.push(open);
+    }
+
+    escaped
+}
+
+/// A CSS length or percentage.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Length {
+    Px(f64),
+    Rem(f64),
+    Em(f64),
+    Percent(f64),
+    Vw(f64),
+    Vh(f64),
+    Auto,
+}
+
+impl fmt::Display for Length {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Px(value) => write!(f, "{value}px"),
+            Self::Rem(value) => write!(f, "{value}rem"),
+            Self::Em(value) => write!(f, "{value}em"),
+            Self::Percent(value) => write!(f, "{value}%"),
+            Self::Vw(value) => write!(f, "{value}vw"),
+            Self::Vh(value) => write!(f, "{value}vh"),
+            Self::Auto => write!(f, "auto"),
+        }
+    }
+}
+
+pub fn px(value: impl Into<f64>) -> Length {
+    Length::Px(value.into())
+}
+
+pub fn rem(value: impl Into<f64>) -> Length {
+    Length::Rem(value.into())
+}
+
+pub fn em(value: impl Into<f64>) -> Length {
+    Length::Em(value.into())
+}
+
+pub fn percent(value: impl Into<f64>) -> Length {
+    Length::Percent(value.into())
+}
+
+pub fn vw(value: impl Into<f64>) -> Length {
+    Length::Vw(value.into())
+}
+
+pub fn vh(value: impl Into<f64>) -> Length {
+    Length::Vh(value.into())
+}
+
+keywords!(Display {
+    Block => "block",
+    Inline => "inline",
+    InlineBlock => "inline-block",
+    Flex => "flex",
+    InlineFlex => "inline-flex",
+    Grid => "grid",
+    InlineGrid => "inline-grid",
+    Contents => "contents",
+    None => "none",
+});
+
+keywords!(Position {
+    Static => "static",
+    Relative => "relative",
+    Absolute => "absolute",
+    Fixed => "fixed",
+    Sticky => "sticky",
+});
+
+keywords!(FlexDirection {
+    Row => "row",
+    RowReverse => "row-reverse",
+    Column => "column",
+    ColumnReverse => "column-reverse",
+});
+
+keywords!(FlexWrap {
+    NoWrap => "nowrap",
+    Wrap => "wrap",
+    WrapReverse => "wrap-reverse",
+});
+
+keywords!(Align {
+    Normal => "normal",
+    Start => "start",
+    End => "end",
+    FlexStart => "flex-start",
+    FlexEnd => "flex-end",
+    Center => "center",
+    Stretch => "stretch",
+    Baseline => "baseline",
+    SpaceBetween => "space-between",
+    SpaceAround => "space-around",
+    SpaceEvenly => "space-evenly",
+});
+
+keywords!(Overflow {
+    Visible => "visible",
+    Hidden => "hidden",
+    Clip => "clip",
+    Scroll => "scroll",
+    Auto => "auto",
+});
+
+keywords!(TextAlign {
+    Left => "left",
+    Right => "right",
+    Center => "center",
+    Justify => "justify",
+    Start => "start",
+    End => "end",
+});
+
+keywords!(Visibility {
+    Visible => "visible",
+    Hidden => "hidden",
+    Collapse => "collapse",
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merges_declarations() {
+        assert_eq!(
+            merge("color: red; margin: 0", "margin: 1px;; padding : 2px"),
+            "color: red; margin: 1px; padding: 2px;"
+        );
+        assert_eq!(merge("", "color: red"), "color: red;");
+        assert_eq!(merge("invalid; color: red", ""), "color: red;");
+    }
+
+    #[test]
+    fn splits_declarations_outside_strings_and_parentheses() {
+        assert_eq!(
+            declarations("a: \"x;y\"; b: url(data:a;b); c: '\\';'; ; d: e").collect::<Vec<_>>(),
+            ["a: \"x;y\"", " b: url(data:a;b)", " c: '\\';'", " d: e"]
+        );
+    }
+
+    #[test]
+    fn lengths() {
+        assert_eq!(px(4).to_string(), "4px");
+        assert_eq!(px(1.5).to_string(), "1.5px");
+        assert_eq!(rem(2).to_string(), "2rem");
+        assert_eq!(em(0.5).to_string(), "0.5em");
+        assert_eq!(percent(50).to_string(), "50%");
+        assert_eq!(vw(100).to_string(), "100vw");
+        assert_eq!(vh(-10).to_string(), "-10vh");
+        assert_eq!(Length::Auto.to_string(), "auto");
+        assert_eq!(
+            InlineStyle::default()
+                .width(px(10))
+                .margin(Length::Auto)
+                .to_string(),
+            "width: 10px; margin: auto;"
+        );
+    }
+
+    #[test]
+    fn escapes_values() {
+        let style = |value: &str| {
+            InlineStyle::default()
+                .property("content", value)
+                .to_string()
+        };
+
+        assert_eq!(style("\"\\201C\""), "content: \"\\201C\";");
+        assert_eq!(style("\"a;b{}\""), "content: \"a;b{}\";");
+        assert_eq!(
+            style("red; position: fixed"),
+            "content: red\\; position: fixed;"
+        );
+        assert_eq!(style("x} body {"), "content: x\\} body \\{;");
+        assert_eq!(
+            style("\"open; color: red"),
+            "content: \"open; color: red\";"
+        );
+        assert_eq!(style("a\\"), "content: a\\\\;");
+        assert_eq!(style("</style>"), "content: \\3c /style>;");
+        assert_eq!(style("\"a\nb\""), "content: \"a\\a b\";");
+        assert_eq!(
+            InlineStyle::default().property("a;b", 1).to_string(),
+            "a\\;b: 1;"
+        );
+    }
+}
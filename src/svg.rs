@@ -0,0 +1,1194 @@
+use crate::node::prelude::*;
+use crate::{element_attribute, element_struct};
+pub const SVG_NAMESPACE: &str = "http://www.w3.org/2000/svg";
+#[doc = r" Presentation attributes, which every element accepts."]
+pub trait Presentation: Element {
+    #[doc = "The paint used to fill the shape, a color, `none` or a `url(#id)` reference."]
+    fn fill(self, value: impl std::fmt::Display) -> Self {
+        self.attribute("fill", value)
+    }
+    #[doc = "The opacity of the fill."]
+    fn fill_opacity(self, value: impl std::fmt::Display) -> Self {
+        self.attribute("fill-opacity", value)
+    }
+    #[doc = "How the inside of a shape is determined, `nonzero` or `evenodd`."]
+    fn fill_rule(self, value: impl std::fmt::Display) -> Self {
+        self.attribute("fill-rule", value)
+    }
+    #[doc = "The paint used to draw the outline of the shape."]
+    fn stroke(self, value: impl std::fmt::Display) -> Self {
+        self.attribute("stroke", value)
+    }
+    #[doc = "The width of the outline."]
+    fn stroke_width(self, value: impl std::fmt::Display) -> Self {
+        self.attribute("stroke-width", value)
+    }
+    #[doc = "The shape at the ends of open paths, `butt`, `round` or `square`."]
+    fn stroke_linecap(self, value: impl std::fmt::Display) -> Self {
+        self.attribute("stroke-linecap", value)
+    }
+    #[doc = "The shape at the corners of paths, `miter`, `round` or `bevel`."]
+    fn stroke_linejoin(self, value: impl std::fmt::Display) -> Self {
+        self.attribute("stroke-linejoin", value)
+    }
+    #[doc = "The limit on the ratio of the miter length to the stroke width."]
+    fn stroke_miterlimit(self, value: impl std::fmt::Display) -> Self {
+        self.attribute("stroke-miterlimit", value)
+    }
+    #[doc = "The pattern of dashes and gaps of the outline."]
+    fn stroke_dasharray(self, value: impl std::fmt::Display) -> Self {
+        self.attribute("stroke-dasharray", value)
+    }
+    #[doc = "The offset of the dash pattern."]
+    fn stroke_dashoffset(self, value: impl std::fmt::Display) -> Self {
+        self.attribute("stroke-dashoffset", value)
+    }
+    #[doc = "The opacity of the outline."]
+    fn stroke_opacity(self, value: impl std::fmt::Display) -> Self {
+        self.attribute("stroke-opacity", value)
+    }
+    #[doc = "The opacity of the element and its children."]
+    fn opacity(self, value: impl std::fmt::Display) -> Self {
+        self.attribute("opacity", value)
+    }
+    #[doc = "Transforms applied to the element and its children, e.g. `rotate(45)`."]
+    fn transform(self, value: impl std::fmt::Display) -> Self {
+        self.attribute("transform", value)
+    }
+    #[doc = "The origin of the element's transforms."]
+    fn transform_origin(self, value: impl std::fmt::Display) -> Self {
+        self.attribute("transform-origin", value)
+    }
+    #[doc = "The clipping path applied, usually a `url(#id)` reference to a `clipPath`."]
+    fn clip_path(self, value: impl std::fmt::Display) -> Self {
+        self.attribute("clip-path", value)
+    }
+    #[doc = "How the inside of a clipping path is determined, `nonzero` or `evenodd`."]
+    fn clip_rule(self, value: impl std::fmt::Display) -> Self {
+        self.attribute("clip-rule", value)
+    }
+    #[doc = "The mask applied, usually a `url(#id)` reference to a `mask`."]
+    fn mask(self, value: impl std::fmt::Display) -> Self {
+        self.attribute("mask", value)
+    }
+    #[doc = "The filter effects applied, usually a `url(#id)` reference to a `filter`."]
+    fn filter(self, value: impl std::fmt::Display) -> Self {
+        self.attribute("filter", value)
+    }
+    #[doc = "The value of `currentcolor`, used by other paint attributes."]
+    fn color(self, value: impl std::fmt::Display) -> Self {
+        self.attribute("color", value)
+    }
+    #[doc = "Whether the element is rendered, e.g. `none`."]
+    fn display(self, value: impl std::fmt::Display) -> Self {
+        self.attribute("display", value)
+    }
+    #[doc = "Whether the element is visible, `visible` or `hidden`."]
+    fn visibility(self, value: impl std::fmt::Display) -> Self {
+        self.attribute("visibility", value)
+    }
+    #[doc = "A vector effect, e.g. `non-scaling-stroke`."]
+    fn vector_effect(self, value: impl std::fmt::Display) -> Self {
+        self.attribute("vector-effect", value)
+    }
+    #[doc = "The order the fill, stroke and markers are painted in."]
+    fn paint_order(self, value: impl std::fmt::Display) -> Self {
+        self.attribute("paint-order", value)
+    }
+    #[doc = "Hints the trade-off made when rendering shapes, e.g. `crispEdges`."]
+    fn shape_rendering(self, value: impl std::fmt::Display) -> Self {
+        self.attribute("shape-rendering", value)
+    }
+    #[doc = "Whether and when the element can be the target of pointer events."]
+    fn pointer_events(self, value: impl std::fmt::Display) -> Self {
+        self.attribute("pointer-events", value)
+    }
+    #[doc = "The marker drawn at the first vertex."]
+    fn marker_start(self, value: impl std::fmt::Display) -> Self {
+        self.attribute("marker-start", value)
+    }
+    #[doc = "The marker drawn at every vertex other than the first and last."]
+    fn marker_mid(self, value: impl std::fmt::Display) -> Self {
+        self.attribute("marker-mid", value)
+    }
+    #[doc = "The marker drawn at the last vertex."]
+    fn marker_end(self, value: impl std::fmt::Display) -> Self {
+        self.attribute("marker-end", value)
+    }
+    #[doc = "The font family of text."]
+    fn font_family(self, value: impl std::fmt::Display) -> Self {
+        self.attribute("font-family", value)
+    }
+    #[doc = "The font size of text."]
+    fn font_size(self, value: impl std::fmt::Display) -> Self {
+        self.attribute("font-size", value)
+    }
+    #[doc = "The font weight of text."]
+    fn font_weight(self, value: impl std::fmt::Display) -> Self {
+        self.attribute("font-weight", value)
+    }
+    #[doc = "How text is aligned to its position, `start`, `middle` or `end`."]
+    fn text_anchor(self, value: impl std::fmt::Display) -> Self {
+        self.attribute("text-anchor", value)
+    }
+    #[doc = "The baseline used to align text."]
+    fn dominant_baseline(self, value: impl std::fmt::Display) -> Self {
+        self.attribute("dominant-baseline", value)
+    }
+}
+element_struct ! (Svg , "svg" , "A container defining a new coordinate system and viewport, used as the outermost element of SVG documents or to embed SVG in HTML.") ;
+element_attribute ! (Svg , view_box , "viewBox" , "The position and dimension, in user space, of the SVG viewport, as `min-x min-y width height`.") ;
+element_attribute ! (Svg , preserve_aspect_ratio , "preserveAspectRatio" , "How the element is deformed when its `viewBox` has a different aspect ratio from the viewport, e.g. `xMidYMid meet`.") ;
+element_attribute!(Svg, x, "x", "The x coordinate of the element.");
+element_attribute!(Svg, y, "y", "The y coordinate of the element.");
+element_attribute!(Svg, width, "width", "The width of the element.");
+element_attribute!(Svg, height, "height", "The height of the element.");
+impl Presentation for Svg {}
+pub fn svg() -> Svg {
+    Svg::new_empty().attribute("xmlns", SVG_NAMESPACE)
+}
+element_struct ! (G , "g" , "A container used to group other SVG elements, transformations applied to it apply to its children.") ;
+impl Presentation for G {}
+pub fn g() -> G {
+    G::new_empty()
+}
+element_struct ! (Defs , "defs" , "Stores graphical objects to be used later, such as gradients and symbols, which aren't rendered directly.") ;
+impl Presentation for Defs {}
+pub fn defs() -> Defs {
+    Defs::new_empty()
+}
+element_struct!(
+    Symbol,
+    "symbol",
+    "Defines a graphical template object which is only rendered by a `use` element."
+);
+element_attribute ! (Symbol , view_box , "viewBox" , "The position and dimension, in user space, of the SVG viewport, as `min-x min-y width height`.") ;
+element_attribute ! (Symbol , preserve_aspect_ratio , "preserveAspectRatio" , "How the element is deformed when its `viewBox` has a different aspect ratio from the viewport, e.g. `xMidYMid meet`.") ;
+element_attribute!(Symbol, x, "x", "The x coordinate of the element.");
+element_attribute!(Symbol, y, "y", "The y coordinate of the element.");
+element_attribute!(Symbol, width, "width", "The width of the element.");
+element_attribute!(Symbol, height, "height", "The height of the element.");
+element_attribute!(
+    Symbol,
+    ref_x,
+    "refX",
+    "The x coordinate of the reference point."
+);
+element_attribute!(
+    Symbol,
+    ref_y,
+    "refY",
+    "The y coordinate of the reference point."
+);
+impl Presentation for Symbol {}
+pub fn symbol() -> Symbol {
+    Symbol::new_empty()
+}
+element_struct!(
+    Use,
+    "use",
+    "Renders a copy of another element, usually a `symbol`, referenced by `href`."
+);
+element_attribute!(
+    Use,
+    href,
+    "href",
+    "A URL or fragment reference to the element or resource used."
+);
+element_attribute!(Use, x, "x", "The x coordinate of the element.");
+element_attribute!(Use, y, "y", "The y coordinate of the element.");
+element_attribute!(Use, width, "width", "The width of the element.");
+element_attribute!(Use, height, "height", "The height of the element.");
+impl Presentation for Use {}
+pub fn r#use() -> Use {
+    Use::new_empty()
+}
+element_struct!(Path, "path", "The generic element to define a shape.");
+element_attribute!(
+    Path,
+    d,
+    "d",
+    "The path data, a series of commands such as `M 10 10 L 20 20 Z`."
+);
+element_attribute!(
+    Path,
+    path_length,
+    "pathLength",
+    "The total length of the path, in user units, used to scale distances along it."
+);
+impl Presentation for Path {}
+pub fn path() -> Path {
+    Path::new_empty()
+}
+element_struct!(
+    Circle,
+    "circle",
+    "A circle based on a center point and a radius."
+);
+element_attribute!(Circle, cx, "cx", "The x coordinate of the center.");
+element_attribute!(Circle, cy, "cy", "The y coordinate of the center.");
+element_attribute!(Circle, r, "r", "The radius.");
+element_attribute!(
+    Circle,
+    path_length,
+    "pathLength",
+    "The total length of the path, in user units, used to scale distances along it."
+);
+impl Presentation for Circle {}
+pub fn circle() -> Circle {
+    Circle::new_empty()
+}
+element_struct!(
+    Ellipse,
+    "ellipse",
+    "An ellipse based on a center point and two radii."
+);
+element_attribute!(Ellipse, cx, "cx", "The x coordinate of the center.");
+element_attribute!(Ellipse, cy, "cy", "The y coordinate of the center.");
+element_attribute!(
+    Ellipse,
+    rx,
+    "rx",
+    "The horizontal radius, or the horizontal corner radius of a rectangle."
+);
+element_attribute!(
+    Ellipse,
+    ry,
+    "ry",
+    "The vertical radius, or the vertical corner radius of a rectangle."
+);
+element_attribute!(
+    Ellipse,
+    path_length,
+    "pathLength",
+    "The total length of the path, in user units, used to scale distances along it."
+);
+impl Presentation for Ellipse {}
+pub fn ellipse() -> Ellipse {
+    Ellipse::new_empty()
+}
+element_struct!(Line, "line", "A line connecting two points.");
+element_attribute!(Line, x1, "x1", "The x coordinate of the start.");
+element_attribute!(Line, y1, "y1", "The y coordinate of the start.");
+element_attribute!(Line, x2, "x2", "The x coordinate of the end.");
+element_attribute!(Line, y2, "y2", "The y coordinate of the end.");
+element_attribute!(
+    Line,
+    path_length,
+    "pathLength",
+    "The total length of the path, in user units, used to scale distances along it."
+);
+impl Presentation for Line {}
+pub fn line() -> Line {
+    Line::new_empty()
+}
+element_struct!(
+    Polyline,
+    "polyline",
+    "A set of connected straight lines, usually an open shape."
+);
+element_attribute!(Polyline, points, "points", "The list of `x,y` points.");
+element_attribute!(
+    Polyline,
+    path_length,
+    "pathLength",
+    "The total length of the path, in user units, used to scale distances along it."
+);
+impl Presentation for Polyline {}
+pub fn polyline() -> Polyline {
+    Polyline::new_empty()
+}
+element_struct!(
+    Polygon,
+    "polygon",
+    "A closed shape made of a set of connected straight lines."
+);
+element_attribute!(Polygon, points, "points", "The list of `x,y` points.");
+element_attribute!(
+    Polygon,
+    path_length,
+    "pathLength",
+    "The total length of the path, in user units, used to scale distances along it."
+);
+impl Presentation for Polygon {}
+pub fn polygon() -> Polygon {
+    Polygon::new_empty()
+}
+element_struct!(
+    Rect,
+    "rect",
+    "A rectangle, optionally with rounded corners."
+);
+element_attribute!(Rect, x, "x", "The x coordinate of the element.");
+element_attribute!(Rect, y, "y", "The y coordinate of the element.");
+element_attribute!(Rect, width, "width", "The width of the element.");
+element_attribute!(Rect, height, "height", "The height of the element.");
+element_attribute!(
+    Rect,
+    rx,
+    "rx",
+    "The horizontal radius, or the horizontal corner radius of a rectangle."
+);
+element_attribute!(
+    Rect,
+    ry,
+    "ry",
+    "The vertical radius, or the vertical corner radius of a rectangle."
+);
+element_attribute!(
+    Rect,
+    path_length,
+    "pathLength",
+    "The total length of the path, in user units, used to scale distances along it."
+);
+impl Presentation for Rect {}
+pub fn rect() -> Rect {
+    Rect::new_empty()
+}
+element_struct!(Text, "text", "A graphics element consisting of text.");
+element_attribute!(Text, x, "x", "The x coordinate of the element.");
+element_attribute!(Text, y, "y", "The y coordinate of the element.");
+element_attribute!(Text, dx, "dx", "A shift along the x axis.");
+element_attribute!(Text, dy, "dy", "A shift along the y axis.");
+element_attribute!(Text, rotate, "rotate", "The rotation of each glyph.");
+element_attribute!(
+    Text,
+    length_adjust,
+    "lengthAdjust",
+    "How the text is stretched to `textLength`, `spacing` or `spacingAndGlyphs`."
+);
+element_attribute!(
+    Text,
+    text_length,
+    "textLength",
+    "The width the text is stretched or compressed to."
+);
+impl Presentation for Text {}
+pub fn text() -> Text {
+    Text::new_empty()
+}
+element_struct!(
+    Tspan,
+    "tspan",
+    "A subtext within a `text` element, positioned or styled separately."
+);
+element_attribute!(Tspan, x, "x", "The x coordinate of the element.");
+element_attribute!(Tspan, y, "y", "The y coordinate of the element.");
+element_attribute!(Tspan, dx, "dx", "A shift along the x axis.");
+element_attribute!(Tspan, dy, "dy", "A shift along the y axis.");
+element_attribute!(Tspan, rotate, "rotate", "The rotation of each glyph.");
+element_attribute!(
+    Tspan,
+    length_adjust,
+    "lengthAdjust",
+    "How the text is stretched to `textLength`, `spacing` or `spacingAndGlyphs`."
+);
+element_attribute!(
+    Tspan,
+    text_length,
+    "textLength",
+    "The width the text is stretched or compressed to."
+);
+impl Presentation for Tspan {}
+pub fn tspan() -> Tspan {
+    Tspan::new_empty()
+}
+element_struct!(
+    TextPath,
+    "textPath",
+    "Renders text along the shape of a `path`."
+);
+element_attribute!(
+    TextPath,
+    href,
+    "href",
+    "A URL or fragment reference to the element or resource used."
+);
+element_attribute!(
+    TextPath,
+    length_adjust,
+    "lengthAdjust",
+    "How the text is stretched to `textLength`, `spacing` or `spacingAndGlyphs`."
+);
+element_attribute!(
+    TextPath,
+    text_length,
+    "textLength",
+    "The width the text is stretched or compressed to."
+);
+element_attribute!(
+    TextPath,
+    method,
+    "method",
+    "Whether glyphs are rendered by `align` or `stretch`."
+);
+element_attribute!(
+    TextPath,
+    side,
+    "side",
+    "Which side of the path the text is placed on, `left` or `right`."
+);
+element_attribute!(
+    TextPath,
+    spacing,
+    "spacing",
+    "How space between glyphs is handled, `auto` or `exact`."
+);
+element_attribute!(
+    TextPath,
+    start_offset,
+    "startOffset",
+    "How far along the path the text starts."
+);
+impl Presentation for TextPath {}
+pub fn text_path() -> TextPath {
+    TextPath::new_empty()
+}
+element_struct!(
+    Image,
+    "image",
+    "Includes an image, such as a PNG, JPEG or another SVG."
+);
+element_attribute!(
+    Image,
+    href,
+    "href",
+    "A URL or fragment reference to the element or resource used."
+);
+element_attribute!(Image, x, "x", "The x coordinate of the element.");
+element_attribute!(Image, y, "y", "The y coordinate of the element.");
+element_attribute!(Image, width, "width", "The width of the element.");
+element_attribute!(Image, height, "height", "The height of the element.");
+element_attribute ! (Image , preserve_aspect_ratio , "preserveAspectRatio" , "How the element is deformed when its `viewBox` has a different aspect ratio from the viewport, e.g. `xMidYMid meet`.") ;
+element_attribute!(
+    Image,
+    crossorigin,
+    "crossorigin",
+    "The CORS setting used to fetch the image."
+);
+element_attribute!(
+    Image,
+    decoding,
+    "decoding",
+    "A hint for decoding the image, `sync`, `async` or `auto`."
+);
+impl Presentation for Image {}
+pub fn image() -> Image {
+    Image::new_empty()
+}
+element_struct!(
+    Title,
+    "title",
+    "An accessible, short-text description of its parent element, often shown as a tooltip."
+);
+impl Presentation for Title {}
+pub fn title() -> Title {
+    Title::new_empty()
+}
+element_struct!(
+    Desc,
+    "desc",
+    "An accessible, long-text description of its parent element."
+);
+impl Presentation for Desc {}
+pub fn desc() -> Desc {
+    Desc::new_empty()
+}
+element_struct!(
+    Metadata,
+    "metadata",
+    "Metadata about the SVG content, such as RDF."
+);
+impl Presentation for Metadata {}
+pub fn metadata() -> Metadata {
+    Metadata::new_empty()
+}
+element_struct!(A, "a", "A hyperlink around its children.");
+element_attribute!(
+    A,
+    href,
+    "href",
+    "A URL or fragment reference to the element or resource used."
+);
+element_attribute!(
+    A,
+    target,
+    "target",
+    "Where to display the linked URL, e.g. `_blank`."
+);
+element_attribute!(
+    A,
+    download,
+    "download",
+    "Prompts to save the linked URL instead of navigating to it."
+);
+element_attribute!(A, hreflang, "hreflang", "The language of the linked URL.");
+element_attribute!(A, ping, "ping", "URLs pinged when the link is followed.");
+element_attribute!(
+    A,
+    referrerpolicy,
+    "referrerpolicy",
+    "Which referrer is sent when following the link."
+);
+element_attribute!(
+    A,
+    rel,
+    "rel",
+    "The relationship of the linked URL to the current document."
+);
+element_attribute!(A, r#type, "type", "The MIME type of the linked URL.");
+impl Presentation for A {}
+pub fn a() -> A {
+    A::new_empty()
+}
+element_struct!(
+    LinearGradient,
+    "linearGradient",
+    "A linear gradient, applied with `fill` or `stroke`."
+);
+element_attribute!(LinearGradient, x1, "x1", "The x coordinate of the start.");
+element_attribute!(LinearGradient, y1, "y1", "The y coordinate of the start.");
+element_attribute!(LinearGradient, x2, "x2", "The x coordinate of the end.");
+element_attribute!(LinearGradient, y2, "y2", "The y coordinate of the end.");
+element_attribute!(
+    LinearGradient,
+    gradient_units,
+    "gradientUnits",
+    "The coordinate system of the gradient attributes, `userSpaceOnUse` or `objectBoundingBox`."
+);
+element_attribute!(
+    LinearGradient,
+    gradient_transform,
+    "gradientTransform",
+    "A transform applied to the gradient."
+);
+element_attribute!(
+    LinearGradient,
+    href,
+    "href",
+    "A URL or fragment reference to the element or resource used."
+);
+element_attribute!(
+    LinearGradient,
+    spread_method,
+    "spreadMethod",
+    "How the gradient behaves outside its bounds, `pad`, `reflect` or `repeat`."
+);
+impl Presentation for LinearGradient {}
+pub fn linear_gradient() -> LinearGradient {
+    LinearGradient::new_empty()
+}
+element_struct!(
+    RadialGradient,
+    "radialGradient",
+    "A radial gradient, applied with `fill` or `stroke`."
+);
+element_attribute!(RadialGradient, cx, "cx", "The x coordinate of the center.");
+element_attribute!(RadialGradient, cy, "cy", "The y coordinate of the center.");
+element_attribute!(RadialGradient, r, "r", "The radius.");
+element_attribute!(
+    RadialGradient,
+    fx,
+    "fx",
+    "The x coordinate of the focal point."
+);
+element_attribute!(
+    RadialGradient,
+    fy,
+    "fy",
+    "The y coordinate of the focal point."
+);
+element_attribute!(RadialGradient, fr, "fr", "The radius of the focal point.");
+element_attribute!(
+    RadialGradient,
+    gradient_units,
+    "gradientUnits",
+    "The coordinate system of the gradient attributes, `userSpaceOnUse` or `objectBoundingBox`."
+);
+element_attribute!(
+    RadialGradient,
+    gradient_transform,
+    "gradientTransform",
+    "A transform applied to the gradient."
+);
+element_attribute!(
+    RadialGradient,
+    href,
+    "href",
+    "A URL or fragment reference to the element or resource used."
+);
+element_attribute!(
+    RadialGradient,
+    spread_method,
+    "spreadMethod",
+    "How the gradient behaves outside its bounds, `pad`, `reflect` or `repeat`."
+);
+impl Presentation for RadialGradient {}
+pub fn radial_gradient() -> RadialGradient {
+    RadialGradient::new_empty()
+}
+element_struct!(Stop, "stop", "A color and its position in a gradient.");
+element_attribute!(
+    Stop,
+    offset,
+    "offset",
+    "Where the gradient stop is placed, from `0` to `1` or a percentage."
+);
+element_attribute!(
+    Stop,
+    stop_color,
+    "stop-color",
+    "The color of the gradient stop."
+);
+element_attribute!(
+    Stop,
+    stop_opacity,
+    "stop-opacity",
+    "The opacity of the gradient stop."
+);
+impl Presentation for Stop {}
+pub fn stop() -> Stop {
+    Stop::new_empty()
+}
+element_struct!(
+    Pattern,
+    "pattern",
+    "A graphic object that is tiled to fill or stroke an object."
+);
+element_attribute!(Pattern, x, "x", "The x coordinate of the element.");
+element_attribute!(Pattern, y, "y", "The y coordinate of the element.");
+element_attribute!(Pattern, width, "width", "The width of the element.");
+element_attribute!(Pattern, height, "height", "The height of the element.");
+element_attribute ! (Pattern , view_box , "viewBox" , "The position and dimension, in user space, of the SVG viewport, as `min-x min-y width height`.") ;
+element_attribute ! (Pattern , preserve_aspect_ratio , "preserveAspectRatio" , "How the element is deformed when its `viewBox` has a different aspect ratio from the viewport, e.g. `xMidYMid meet`.") ;
+element_attribute!(
+    Pattern,
+    href,
+    "href",
+    "A URL or fragment reference to the element or resource used."
+);
+element_attribute!(
+    Pattern,
+    pattern_units,
+    "patternUnits",
+    "The coordinate system of `x`, `y`, `width` and `height`."
+);
+element_attribute!(
+    Pattern,
+    pattern_content_units,
+    "patternContentUnits",
+    "The coordinate system of the pattern's contents."
+);
+element_attribute!(
+    Pattern,
+    pattern_transform,
+    "patternTransform",
+    "A transform applied to the pattern."
+);
+impl Presentation for Pattern {}
+pub fn pattern() -> Pattern {
+    Pattern::new_empty()
+}
+element_struct!(
+    ClipPath,
+    "clipPath",
+    "A clipping path, applied with `clip-path`."
+);
+element_attribute!(
+    ClipPath,
+    clip_path_units,
+    "clipPathUnits",
+    "The coordinate system of the clipping path's contents."
+);
+impl Presentation for ClipPath {}
+pub fn clip_path() -> ClipPath {
+    ClipPath::new_empty()
+}
+element_struct!(
+    Mask,
+    "mask",
+    "An alpha mask for compositing the current object into the background, applied with `mask`."
+);
+element_attribute!(Mask, x, "x", "The x coordinate of the element.");
+element_attribute!(Mask, y, "y", "The y coordinate of the element.");
+element_attribute!(Mask, width, "width", "The width of the element.");
+element_attribute!(Mask, height, "height", "The height of the element.");
+element_attribute!(
+    Mask,
+    mask_units,
+    "maskUnits",
+    "The coordinate system of `x`, `y`, `width` and `height`."
+);
+element_attribute!(
+    Mask,
+    mask_content_units,
+    "maskContentUnits",
+    "The coordinate system of the mask's contents."
+);
+impl Presentation for Mask {}
+pub fn mask() -> Mask {
+    Mask::new_empty()
+}
+element_struct ! (Marker , "marker" , "The graphic drawn at the vertices of a shape, applied with `marker-start`, `marker-mid` or `marker-end`.") ;
+element_attribute ! (Marker , view_box , "viewBox" , "The position and dimension, in user space, of the SVG viewport, as `min-x min-y width height`.") ;
+element_attribute ! (Marker , preserve_aspect_ratio , "preserveAspectRatio" , "How the element is deformed when its `viewBox` has a different aspect ratio from the viewport, e.g. `xMidYMid meet`.") ;
+element_attribute!(
+    Marker,
+    ref_x,
+    "refX",
+    "The x coordinate of the reference point."
+);
+element_attribute!(
+    Marker,
+    ref_y,
+    "refY",
+    "The y coordinate of the reference point."
+);
+element_attribute!(
+    Marker,
+    marker_width,
+    "markerWidth",
+    "The width of the marker viewport."
+);
+element_attribute!(
+    Marker,
+    marker_height,
+    "markerHeight",
+    "The height of the marker viewport."
+);
+element_attribute!(
+    Marker,
+    marker_units,
+    "markerUnits",
+    "The coordinate system of `markerWidth` and `markerHeight`."
+);
+element_attribute!(
+    Marker,
+    orient,
+    "orient",
+    "How the marker is rotated, an angle or `auto`."
+);
+impl Presentation for Marker {}
+pub fn marker() -> Marker {
+    Marker::new_empty()
+}
+element_struct!(
+    Filter,
+    "filter",
+    "A filter effect made of filter primitives, applied with `filter`."
+);
+element_attribute!(Filter, x, "x", "The x coordinate of the element.");
+element_attribute!(Filter, y, "y", "The y coordinate of the element.");
+element_attribute!(Filter, width, "width", "The width of the element.");
+element_attribute!(Filter, height, "height", "The height of the element.");
+element_attribute!(
+    Filter,
+    filter_units,
+    "filterUnits",
+    "The coordinate system of `x`, `y`, `width` and `height`."
+);
+element_attribute!(
+    Filter,
+    primitive_units,
+    "primitiveUnits",
+    "The coordinate system of the filter primitives."
+);
+impl Presentation for Filter {}
+pub fn filter() -> Filter {
+    Filter::new_empty()
+}
+element_struct!(
+    FeGaussianBlur,
+    "feGaussianBlur",
+    "A filter primitive that blurs its input."
+);
+element_attribute!(
+    FeGaussianBlur,
+    r#in,
+    "in",
+    "The input of the filter primitive, e.g. `SourceGraphic` or the `result` of an earlier one."
+);
+element_attribute!(
+    FeGaussianBlur,
+    result,
+    "result",
+    "The name of the result of the filter primitive, for use as the `in` of a later one."
+);
+element_attribute!(
+    FeGaussianBlur,
+    std_deviation,
+    "stdDeviation",
+    "The standard deviation of the blur."
+);
+element_attribute!(
+    FeGaussianBlur,
+    edge_mode,
+    "edgeMode",
+    "How the image is extended at its edges."
+);
+impl Presentation for FeGaussianBlur {}
+pub fn fe_gaussian_blur() -> FeGaussianBlur {
+    FeGaussianBlur::new_empty()
+}
+element_struct!(
+    FeOffset,
+    "feOffset",
+    "A filter primitive that offsets its input."
+);
+element_attribute!(
+    FeOffset,
+    r#in,
+    "in",
+    "The input of the filter primitive, e.g. `SourceGraphic` or the `result` of an earlier one."
+);
+element_attribute!(FeOffset, dx, "dx", "A shift along the x axis.");
+element_attribute!(FeOffset, dy, "dy", "A shift along the y axis.");
+element_attribute!(
+    FeOffset,
+    result,
+    "result",
+    "The name of the result of the filter primitive, for use as the `in` of a later one."
+);
+impl Presentation for FeOffset {}
+pub fn fe_offset() -> FeOffset {
+    FeOffset::new_empty()
+}
+element_struct!(
+    FeBlend,
+    "feBlend",
+    "A filter primitive that blends two inputs."
+);
+element_attribute!(
+    FeBlend,
+    r#in,
+    "in",
+    "The input of the filter primitive, e.g. `SourceGraphic` or the `result` of an earlier one."
+);
+element_attribute!(
+    FeBlend,
+    in2,
+    "in2",
+    "The second input of the filter primitive."
+);
+element_attribute!(
+    FeBlend,
+    result,
+    "result",
+    "The name of the result of the filter primitive, for use as the `in` of a later one."
+);
+element_attribute!(FeBlend, mode, "mode", "The blend mode, e.g. `multiply`.");
+impl Presentation for FeBlend {}
+pub fn fe_blend() -> FeBlend {
+    FeBlend::new_empty()
+}
+element_struct!(
+    FeColorMatrix,
+    "feColorMatrix",
+    "A filter primitive that transforms colors with a matrix."
+);
+element_attribute!(
+    FeColorMatrix,
+    r#in,
+    "in",
+    "The input of the filter primitive, e.g. `SourceGraphic` or the `result` of an earlier one."
+);
+element_attribute!(
+    FeColorMatrix,
+    result,
+    "result",
+    "The name of the result of the filter primitive, for use as the `in` of a later one."
+);
+element_attribute!(
+    FeColorMatrix,
+    r#type,
+    "type",
+    "The kind of matrix, `matrix`, `saturate`, `hueRotate` or `luminanceToAlpha`."
+);
+element_attribute!(FeColorMatrix, values, "values", "The values of the matrix.");
+impl Presentation for FeColorMatrix {}
+pub fn fe_color_matrix() -> FeColorMatrix {
+    FeColorMatrix::new_empty()
+}
+element_struct!(
+    FeFlood,
+    "feFlood",
+    "A filter primitive that fills the filter region with a color."
+);
+element_attribute!(
+    FeFlood,
+    result,
+    "result",
+    "The name of the result of the filter primitive, for use as the `in` of a later one."
+);
+element_attribute!(
+    FeFlood,
+    flood_color,
+    "flood-color",
+    "The color of the fill."
+);
+element_attribute!(
+    FeFlood,
+    flood_opacity,
+    "flood-opacity",
+    "The opacity of the fill."
+);
+impl Presentation for FeFlood {}
+pub fn fe_flood() -> FeFlood {
+    FeFlood::new_empty()
+}
+element_struct!(
+    FeComposite,
+    "feComposite",
+    "A filter primitive that combines two inputs with a Porter-Duff operation."
+);
+element_attribute!(
+    FeComposite,
+    r#in,
+    "in",
+    "The input of the filter primitive, e.g. `SourceGraphic` or the `result` of an earlier one."
+);
+element_attribute!(
+    FeComposite,
+    in2,
+    "in2",
+    "The second input of the filter primitive."
+);
+element_attribute!(
+    FeComposite,
+    result,
+    "result",
+    "The name of the result of the filter primitive, for use as the `in` of a later one."
+);
+element_attribute!(
+    FeComposite,
+    operator,
+    "operator",
+    "The compositing operation, e.g. `over` or `in`."
+);
+element_attribute!(
+    FeComposite,
+    k1,
+    "k1",
+    "A coefficient of the `arithmetic` operator."
+);
+element_attribute!(
+    FeComposite,
+    k2,
+    "k2",
+    "A coefficient of the `arithmetic` operator."
+);
+element_attribute!(
+    FeComposite,
+    k3,
+    "k3",
+    "A coefficient of the `arithmetic` operator."
+);
+element_attribute!(
+    FeComposite,
+    k4,
+    "k4",
+    "A coefficient of the `arithmetic` operator."
+);
+impl Presentation for FeComposite {}
+pub fn fe_composite() -> FeComposite {
+    FeComposite::new_empty()
+}
+element_struct!(
+    FeMerge,
+    "feMerge",
+    "A filter primitive that layers its `feMergeNode` inputs."
+);
+element_attribute!(
+    FeMerge,
+    result,
+    "result",
+    "The name of the result of the filter primitive, for use as the `in` of a later one."
+);
+impl Presentation for FeMerge {}
+pub fn fe_merge() -> FeMerge {
+    FeMerge::new_empty()
+}
+element_struct!(FeMergeNode, "feMergeNode", "An input of an `feMerge`.");
+element_attribute!(
+    FeMergeNode,
+    r#in,
+    "in",
+    "The input of the filter primitive, e.g. `SourceGraphic` or the `result` of an earlier one."
+);
+impl Presentation for FeMergeNode {}
+pub fn fe_merge_node() -> FeMergeNode {
+    FeMergeNode::new_empty()
+}
+element_struct!(
+    ForeignObject,
+    "foreignObject",
+    "Includes elements from another namespace, usually HTML."
+);
+element_attribute!(ForeignObject, x, "x", "The x coordinate of the element.");
+element_attribute!(ForeignObject, y, "y", "The y coordinate of the element.");
+element_attribute!(ForeignObject, width, "width", "The width of the element.");
+element_attribute!(
+    ForeignObject,
+    height,
+    "height",
+    "The height of the element."
+);
+impl Presentation for ForeignObject {}
+pub fn foreign_object() -> ForeignObject {
+    ForeignObject::new_empty()
+}
+element_struct!(
+    Animate,
+    "animate",
+    "Animates an attribute of its parent element."
+);
+element_attribute!(
+    Animate,
+    attribute_name,
+    "attributeName",
+    "The attribute to animate."
+);
+element_attribute!(Animate, from, "from", "The starting value.");
+element_attribute!(Animate, to, "to", "The ending value.");
+element_attribute!(Animate, by, "by", "A relative offset of the value.");
+element_attribute!(
+    Animate,
+    values,
+    "values",
+    "The values to animate through, separated by semicolons."
+);
+element_attribute!(
+    Animate,
+    dur,
+    "dur",
+    "The duration of the animation, e.g. `2s`."
+);
+element_attribute!(Animate, begin, "begin", "When the animation begins.");
+element_attribute!(Animate, end, "end", "When the animation ends.");
+element_attribute!(
+    Animate,
+    repeat_count,
+    "repeatCount",
+    "How many times the animation repeats, a number or `indefinite`."
+);
+impl Presentation for Animate {}
+pub fn animate() -> Animate {
+    Animate::new_empty()
+}
+element_struct!(
+    AnimateTransform,
+    "animateTransform",
+    "Animates the `transform` attribute of its parent element."
+);
+element_attribute!(
+    AnimateTransform,
+    attribute_name,
+    "attributeName",
+    "The attribute to animate, usually `transform`."
+);
+element_attribute!(
+    AnimateTransform,
+    r#type,
+    "type",
+    "The kind of transform, `translate`, `scale`, `rotate`, `skewX` or `skewY`."
+);
+element_attribute!(AnimateTransform, from, "from", "The starting value.");
+element_attribute!(AnimateTransform, to, "to", "The ending value.");
+element_attribute!(
+    AnimateTransform,
+    by,
+    "by",
+    "A relative offset of the value."
+);
+element_attribute!(
+    AnimateTransform,
+    values,
+    "values",
+    "The values to animate through, separated by semicolons."
+);
+element_attribute!(
+    AnimateTransform,
+    dur,
+    "dur",
+    "The duration of the animation, e.g. `2s`."
+);
+element_attribute!(
+    AnimateTransform,
+    begin,
+    "begin",
+    "When the animation begins."
+);
+element_attribute!(AnimateTransform, end, "end", "When the animation ends.");
+element_attribute!(
+    AnimateTransform,
+    repeat_count,
+    "repeatCount",
+    "How many times the animation repeats, a number or `indefinite`."
+);
+impl Presentation for AnimateTransform {}
+pub fn animate_transform() -> AnimateTransform {
+    AnimateTransform::new_empty()
+}
+element_struct!(Set, "set", "Sets the value of an attribute for a duration.");
+element_attribute!(
+    Set,
+    attribute_name,
+    "attributeName",
+    "The attribute to set."
+);
+element_attribute!(Set, to, "to", "The value to set.");
+element_attribute!(Set, begin, "begin", "When the value is set.");
+element_attribute!(Set, dur, "dur", "How long the value is set for.");
+impl Presentation for Set {}
+pub fn set() -> Set {
+    Set::new_empty()
+}
+element_struct!(
+    Switch,
+    "switch",
+    "Renders the first child whose conditions, such as `systemLanguage`, are met."
+);
+impl Presentation for Switch {}
+pub fn switch() -> Switch {
+    Switch::new_empty()
+}
+element_struct!(
+    View,
+    "view",
+    "A way to view the image, like a zoom level, referenced by a fragment identifier."
+);
+element_attribute ! (View , view_box , "viewBox" , "The position and dimension, in user space, of the SVG viewport, as `min-x min-y width height`.") ;
+element_attribute ! (View , preserve_aspect_ratio , "preserveAspectRatio" , "How the element is deformed when its `viewBox` has a different aspect ratio from the viewport, e.g. `xMidYMid meet`.") ;
+impl Presentation for View {}
+pub fn view() -> View {
+    View::new_empty()
+}
+element_struct!(Style, "style", "Embeds CSS in the SVG.");
+element_attribute!(
+    Style,
+    r#type,
+    "type",
+    "The style sheet language, `text/css` by default."
+);
+element_attribute!(Style, media, "media", "The media the styles apply to.");
+impl Presentation for Style {}
+pub fn style() -> Style {
+    Style::new_empty()
+}
+element_struct!(Script, "script", "Embeds a script in the SVG.");
+element_attribute!(
+    Script,
+    href,
+    "href",
+    "A URL or fragment reference to the element or resource used."
+);
+element_attribute!(
+    Script,
+    r#type,
+    "type",
+    "The scripting language, JavaScript by default."
+);
+impl Presentation for Script {}
+pub fn script() -> Script {
+    Script::new_empty()
+}
@@ -0,0 +1,314 @@
+use crate::{
+    aria::Sort,
+    macros::keywords,
+    prelude::{a, Caption, Col, Colgroup, Table, Tbody, Td, Tfoot, Th, Thead, Tr},
+    Children, Element, GenericElement, Node,
+};
+use std::{borrow::Borrow, fmt::Display};
+
+pub fn table() -> Table {
+    Table::new_empty()
+}
+
+pub fn caption(text: impl Display) -> Caption {
+    Caption::new_empty().text(text)
+}
+
+pub fn colgroup() -> Colgroup {
+    Colgroup::new_empty()
+}
+
+pub fn col() -> Col {
+    Col::new_empty()
+}
+
+pub fn thead() -> Thead {
+    Thead::new_empty()
+}
+
+pub fn tbody() -> Tbody {
+    Tbody::new_empty()
+}
+
+pub fn tfoot() -> Tfoot {
+    Tfoot::new_empty()
+}
+
+pub fn tr() -> Tr {
+    Tr::new_empty()
+}
+
+pub fn th() -> Th {
+    Th::new_empty()
+}
+
+pub fn td() -> Td {
+    Td::new_empty()
+}
+
+keywords!(
+    /// The cells a header cell applies to, see [`Th::scope`](crate::prelude::Th::scope).
+    Scope {
+        Row => "row",
+        Col => "col",
+        RowGroup => "rowgroup",
+        ColGroup => "colgroup",
+    }
+);
+
+type Footer<'a, R> = Box<dyn Fn(&[R]) -> Node + 'a>;
+
+/// A column of a [`table_from`] table.
+pub struct Column<'a, R> {
+    header: Node,
+    cell: Box<dyn Fn(&R) -> Node + 'a>,
+    footer: Option<Footer<'a, R>>,
+    sort_key: Option<String>,
+    class: Option<String>,
+}
+
+/// A column with a header and a function building the cell for each row.
+///
+/// Cells that are `td` or `th` elements are used as they are, so they can set `colspan`,
+/// `rowspan` or be row headers, anything else is wrapped in a `td`.
+pub fn column<'a, R, H, C>(header: H, cell: impl Fn(&R) -> C + 'a) -> Column<'a, R>
+where
+    Node: From<H> + From<C>,
+{
+    Column {
+        header: header.into(),
+        cell: Box::new(move |row| cell(row).into()),
+        footer: None,
+        sort_key: None,
+        class: None,
+    }
+}
+
+impl<'a, R, H, C, F> From<(H, F)> for Column<'a, R>
+where
+    Node: From<H> + From<C>,
+    F: Fn(&R) -> C + 'a,
+{
+    fn from((header, cell): (H, F)) -> Self {
+        column(header, cell)
+    }
+}
+
+impl<'a, R> Column<'a, R> {
+    /// Adds a footer cell computed from all rows, e.g. a total.
+    pub fn footer<F>(mut self, footer: impl Fn(&[R]) -> F + 'a) -> Self
+    where
+        Node: From<F>,
+    {
+        self.footer = Some(Box::new(move |rows| footer(rows).into()));
+        self
+    }
+
+    /// Makes the header a link that sorts by `key`, see [`TableFrom::sort_url`].
+    pub fn sortable(mut self, key: impl Display) -> Self {
+        self.sort_key = Some(key.to_string());
+        self
+    }
+
+    /// Adds a `col` for the column with this class, to style the whole column.
+    pub fn class(mut self, class: impl Display) -> Self {
+        self.class = Some(class.to_string());
+        self
+    }
+}
+
+/// A type with a column for each field, usually implemented with `#[derive(Table)]`.
+pub trait TableRow {
+    /// The columns for rows that borrow as `Self`, e.g. `Self` or `&Self`.
+    fn columns<'a, R>() -> Vec<Column<'a, R>>
+    where
+        R: Borrow<Self> + 'a,
+        Self: 'a;
+
+    /// Builds a table of rows, e.g. `User::table(&users).caption(caption("Users"))`.
+    fn table<'a, R>(rows: impl IntoIterator<Item = R>) -> TableFrom<'a, R>
+    where
+        R: Borrow<Self> + 'a,
+        Self: 'a,
+    {
+        table_from(rows, Self::columns())
+    }
+}
+
+/// A table built from rows and columns, see [`table_from`].
+pub struct TableFrom<'a, R> {
+    rows: Vec<R>,
+    columns: Vec<Column<'a, R>>,
+    caption: Option<Caption>,
+    sort_url: Option<String>,
+    sorted_by: Option<(String, Sort)>,
+}
+
+/// Builds a table with a header row, a row per item and a footer if any column has one, e.g.
+/// `table_from(&users, [column("Name", |user: &&User| user.name.clone())])`.
+pub fn table_from<'a, R, C>(
+    rows: impl IntoIterator<Item = R>,
+    columns: impl IntoIterator<Item = C>,
+) -> TableFrom<'a, R>
+where
+    C: Into<Column<'a, R>>,
+{
+    TableFrom {
+        rows: rows.into_iter().collect(),
+        columns: columns.into_iter().map(Into::into).collect(),
+        caption: None,
+        sort_url: None,
+        sorted_by: None,
+    }
+}
+
+impl<'a, R> TableFrom<'a, R> {
+    pub fn caption(mut self, caption: Caption) -> Self {
+        self.caption = Some(caption);
+        self
+    }
+
+    /// Links sortable column headers to `url` with `sort` and `order` query parameters, loaded
+    /// with htmx to replace the table.
+    pub fn sort_url(mut self, url: impl Display) -> Self {
+        self.sort_url = Some(url.to_string());
+        self
+    }
+
+    /// Marks the column the rows are currently sorted by with `aria-sort`.
+    pub fn sorted_by(mut self, key: impl Display, order: Sort) -> Self {
+        self.sorted_by = Some((key.to_string(), order));
+        self
+    }
+
+    pub fn build(mut self) -> Table {
+        let mut table = table();
+        if let Some(caption) = self.caption.take() {
+            table = table.child(caption);
+        }
+
+        if self.columns.iter().any(|column| column.class.is_some()) {
+            table = table.child(
+                colgroup().children(
+                    self.columns
+                        .iter()
+                        .map(|column| col().maybe_attribute("class", column.class.as_ref())),
+                ),
+            );
+        }
+
+        let header = tr().children(self.columns.iter().map(|column| self.header_cell(column)));
+        table = table.child(thead().child(header));
+
+        table =
+            table.child(tbody().children(self.rows.iter().map(|row| {
+                tr().children(self.columns.iter().map(|column| cell((column.cell)(row))))
+            })));
+
+        if self.columns.iter().any(|column| column.footer.is_some()) {
+            let footer = tr().children(self.columns.iter().map(|column| match &column.footer {
+                Some(footer) => cell(footer(&self.rows)),
+                None => td().into(),
+            }));
+            table = table.child(tfoot().child(footer));
+        }
+
+        table
+    }
+
+    fn header_cell(&self, column: &Column<'a, R>) -> Node {
+        let mut header = match column.header.clone() {
+            Node::Element(element) if element.tag_name == "th" => element,
+            header => GenericElement {
+                tag_name: "th".to_string(),
+                attributes: Default::default(),
+                children: vec![header],
+            },
+        };
+        if header.attributes.get("scope").is_none() {
+            header
+                .attributes
+                .set("scope".to_string(), Scope::Col.to_string());
+        }
+
+        let Some(key) = &column.sort_key else {
+            return header.into();
+        };
+        let order = match &self.sorted_by {
+            Some((sorted, order)) if sorted == key => *order,
+            _ => Sort::None,
+        };
+        header
+            .attributes
+            .set("aria-sort".to_string(), order.to_string());
+
+        if let Some(url) = &self.sort_url {
+            let next = if order == Sort::Ascending {
+                "desc"
+            } else {
+                "asc"
+            };
+            let separator = if url.contains('?') { '&' } else { '?' };
+            let key = form_urlencoded::byte_serialize(key.as_bytes()).collect::<String>();
+            let href = format!("{url}{separator}sort={key}&order={next}");
+            let link = a(&href)
+                .hx_get(&href)
+                .hx_target("closest table")
+                .hx_swap("outerHTML")
+                .children(std::mem::take(&mut header.children));
+            header.push_child(link.into());
+        }
+        header.into()
+    }
+}
+
+impl<'a, R> From<TableFrom<'a, R>> for Node {
+    fn from(value: TableFrom<'a, R>) -> Self {
+        value.build().into()
+    }
+}
+
+/// Wraps a node in a `td` unless it already is a table cell.
+fn cell(node: Node) -> Node {
+    match node.tag_name() {
+        Some("td" | "th") => node,
+        _ => td().child(node).into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sort_links_encode_key() {
+        let rows = [("a", 1)];
+        let table: Node = table_from(
+            rows,
+            [
+                column("Name", |row: &(&str, i32)| row.0).sortable("name&age"),
+                column("Age", |row: &(&str, i32)| row.1.to_string()).sortable("age"),
+            ],
+        )
+        .sort_url("/users?page=2")
+        .sorted_by("age", Sort::Ascending)
+        .build()
+        .into();
+
+        let hrefs = table
+            .select("th a")
+            .into_iter()
+            .map(|link| {
+                link.attributes()
+                    .and_then(|attributes| attributes.get("href"))
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            hrefs,
+            [
+                Some("/users?page=2&sort=name%26age&order=asc"),
+                Some("/users?page=2&sort=age&order=desc"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,358 @@
+use crate::{node::child_paths, Attributes, GenericElement, Node};
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    sync::{PoisonError, RwLock},
+};
+
+/// A content model rule broken by a tree, found by [`check`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    pub message: String,
+    /// A selector for the offending node, e.g. `body > p:nth-child(2) > div`.
+    pub path: String,
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// What [`Element::response`](crate::Element::response) does with violations in debug builds.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum Policy {
+    #[default]
+    Ignore,
+    /// Passes the violations to a caller-supplied hook, e.g. to log them.
+    Warn(fn(&[Violation])),
+    /// Panics with a report of the violations, failing the request or test that rendered them.
+    Panic,
+}
+
+static POLICY: RwLock<Policy> = RwLock::new(Policy::Ignore);
+
+pub fn set_policy(policy: Policy) {
+    *POLICY.write().unwrap_or_else(PoisonError::into_inner) = policy;
+}
+
+/// The current policy, [`Policy::Ignore`] unless changed with [`set_policy`].
+pub fn policy() -> Policy {
+    *POLICY.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Validates a document in debug builds according to the current [`Policy`].
+pub(crate) fn debug_check(element: &GenericElement) {
+    if cfg!(debug_assertions) {
+        apply_policy(policy(), element);
+    }
+}
+
+fn apply_policy(policy: Policy, element: &GenericElement) {
+    if matches!(policy, Policy::Ignore) {
+        return;
+    }
+
+    let violations = check_element(element);
+    if violations.is_empty() {
+        return;
+    }
+
+    match policy {
+        Policy::Ignore => {}
+        Policy::Warn(hook) => hook(&violations),
+        Policy::Panic => {
+            let report = violations
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join("\n");
+            panic!("invalid HTML:\n{report}")
+        }
+    }
+}
+
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+    "wbr",
+];
+
+/// Elements that end an open `p` element, so cannot be inside one.
+const BLOCK_ELEMENTS: &[&str] = &[
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "details",
+    "dialog",
+    "div",
+    "dl",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hgroup",
+    "hr",
+    "main",
+    "menu",
+    "nav",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "table",
+    "ul",
+];
+
+const REQUIRED_ATTRIBUTES: &[(&str, &[&str])] = &[
+    ("img", &["src", "alt"]),
+    ("link", &["rel", "href"]),
+    ("optgroup", &["label"]),
+    ("track", &["src"]),
+    ("bdo", &["dir"]),
+    ("data", &["value"]),
+    ("meter", &["value"]),
+];
+
+fn is_interactive(tag_name: &str, attributes: &Attributes) -> bool {
+    match tag_name {
+        "a" => attributes.get("href").is_some(),
+        "input" => attributes.get("type") != Some("hidden"),
+        "audio" | "video" => attributes.get("controls").is_some(),
+        "img" => attributes.get("usemap").is_some(),
+        "button" | "details" | "embed" | "iframe" | "label" | "select" | "textarea" => true,
+        _ => attributes.get("tabindex").is_some(),
+    }
+}
+
+/// Checks a tree against HTML content model rules.
+pub fn check(node: &Node) -> Vec<Violation> {
+    let mut validator = Validator::default();
+    validator.count_ids(node);
+    validator.visit(node, node.tag_name().unwrap_or_default().to_string(), &[]);
+    validator.violations
+}
+
+/// Like [`check`], for an element that hasn't been converted into a [`Node`].
+pub fn check_element(element: &GenericElement) -> Vec<Violation> {
+    let mut validator = Validator::default();
+    validator.count_element_ids(&element.attributes, &element.children);
+    validator.visit_element(
+        &element.tag_name,
+        &element.attributes,
+        Some(&element.children),
+        element.tag_name.clone(),
+        &[],
+    );
+    validator.violations
+}
+
+#[derive(Default)]
+struct Validator {
+    violations: Vec<Violation>,
+    ids: HashMap<String, usize>,
+}
+
+impl Validator {
+    fn report(&mut self, path: &str, message: impl Display) {
+        self.violations.push(Violation {
+            message: message.to_string(),
+            path: path.to_string(),
+        });
+    }
+
+    fn count_ids(&mut self, node: &Node) {
+        match node.attributes() {
+            Some(attributes) => self.count_element_ids(attributes, node.children()),
+            None => node
+                .children()
+                .iter()
+                .for_each(|child| self.count_ids(child)),
+        }
+    }
+
+    fn count_element_ids(&mut self, attributes: &Attributes, children: &[Node]) {
+        if let Some(id) = attributes.get("id") {
+            *self.ids.entry(id.to_string()).or_default() += 1;
+        }
+        for child in children {
+            self.count_ids(child);
+        }
+    }
+
+    /// `ancestors` are the tag names of the node's ancestors, outermost first.
+    fn visit(&mut self, node: &Node, path: String, ancestors: &[&str]) {
+        let (Some(tag_name), Some(attributes)) = (node.tag_name(), node.attributes()) else {
+            // A fragment's children take its place in the parent.
+            if let Node::Fragment(nodes) = node {
+                for (child, path) in child_paths(&path, nodes) {
+                    self.visit(child, path, ancestors);
+                }
+            }
+            return;
+        };
+        let children = match node {
+            Node::Element(element) => Some(element.children.as_slice()),
+            _ => None,
+        };
+        self.visit_element(tag_name, attributes, children, path, ancestors);
+    }
+
+    /// `children` is `None` for void elements, which have no closing tag.
+    fn visit_element(
+        &mut self,
+        tag_name: &str,
+        attributes: &Attributes,
+        children: Option<&[Node]>,
+        path: String,
+        ancestors: &[&str],
+    ) {
+        if let Some(children) = children {
+            if VOID_ELEMENTS.contains(&tag_name) {
+                let message = if children.is_empty() {
+                    format!("void element `{tag_name}` has a closing tag")
+                } else {
+                    format!("void element `{tag_name}` cannot have children")
+                };
+                self.report(&path, message);
+            }
+        }
+
+        if BLOCK_ELEMENTS.contains(&tag_name) && ancestors.contains(&"p") {
+            self.report(&path, format!("`{tag_name}` cannot be inside `p`"));
+        }
+
+        if is_interactive(tag_name, attributes) {
+            if let Some(ancestor) = ancestors
+                .iter()
+                .rev()
+                .find(|ancestor| matches!(**ancestor, "a" | "button"))
+            {
+                self.report(
+                    &path,
+                    format!("interactive `{tag_name}` cannot be inside `{ancestor}`"),
+                );
+            }
+        }
+
+        for (element, required) in REQUIRED_ATTRIBUTES {
+            if *element == tag_name {
+                for attribute in *required {
+                    if attributes.get(attribute).is_none() {
+                        self.report(
+                            &path,
+                            format!("`{tag_name}` is missing required attribute `{attribute}`"),
+                        );
+                    }
+                }
+            }
+        }
+
+        if let Some(id) = attributes.get("id") {
+            if self.ids.get(id).is_some_and(|count| *count > 1) {
+                self.report(&path, format!("duplicate id {id:?}"));
+            }
+        }
+
+        let children = children.unwrap_or_default();
+        let element_children = children
+            .iter()
+            .filter_map(Node::tag_name)
+            .collect::<Vec<_>>();
+        match tag_name {
+            "details" if element_children.first() != Some(&"summary") => {
+                self.report(&path, "`details` must start with a `summary`");
+            }
+            "fieldset"
+                if element_children.contains(&"legend")
+                    && element_children.first() != Some(&"legend") =>
+            {
+                self.report(&path, "`legend` must be the first child of `fieldset`");
+            }
+            _ => {}
+        }
+
+        let ancestors = [ancestors, &[tag_name]].concat();
+        for (child, path) in child_paths(&path, children) {
+            self.visit(child, path, &ancestors);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{body, div, html, img, p, Children, Element};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn reports_violations() {
+        let page = html("en").child(body().child(p("").child(div())).child(img("a.png", "")));
+        let messages = check(&page.into())
+            .into_iter()
+            .map(|violation| violation.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            messages,
+            ["html > body > p:nth-child(1) > div: `div` cannot be inside `p`"]
+        );
+    }
+
+    #[test]
+    fn checks_fragment_children() {
+        let fragment = Node::Fragment(vec![
+            div().into(),
+            Node::Fragment(vec![p("").child(div()).into()]),
+        ]);
+        let messages = check(&fragment)
+            .into_iter()
+            .map(|violation| violation.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            messages,
+            ["p:nth-child(2) > div: `div` cannot be inside `p`"]
+        );
+    }
+
+    #[test]
+    fn applies_policy() {
+        static REPORTED: AtomicUsize = AtomicUsize::new(0);
+
+        let page = html("en").child(body().child(p("").child(div())));
+        let page = page.as_generic_element().unwrap();
+        apply_policy(
+            Policy::Warn(|violations| {
+                REPORTED.fetch_add(violations.len(), Ordering::Relaxed);
+            }),
+            page,
+        );
+        assert_eq!(REPORTED.load(Ordering::Relaxed), 1);
+
+        apply_policy(Policy::Ignore, page);
+        assert!(std::panic::catch_unwind(|| apply_policy(Policy::Panic, page)).is_err());
+        apply_policy(
+            Policy::Panic,
+            html("en")
+                .child(body().child(p("")))
+                .as_generic_element()
+                .unwrap(),
+        );
+    }
+
+    #[test]
+    fn response_ignores_violations_by_default() {
+        assert!(matches!(policy(), Policy::Ignore));
+        html("en")
+            .child(body().child(p("").child(div())))
+            .response();
+        body().child(p("").child(div())).response();
+    }
+}
@@ -0,0 +1,410 @@
+use crate::{GenericElement, Node, VoidElement};
+
+/// Read-only traversal of a tree, see [`Node::visit`].
+///
+/// Nodes are visited depth first in document order: `enter` is called on a node before any of
+/// its descendants, and `leave` after all of them.
+pub trait Visitor {
+    /// Returning `false` skips the node's descendants, `leave` is still called.
+    fn enter(&mut self, _node: &Node) -> bool {
+        true
+    }
+
+    fn leave(&mut self, _node: &Node) {}
+}
+
+impl<F: FnMut(&Node)> Visitor for F {
+    fn enter(&mut self, node: &Node) -> bool {
+        self(node);
+        true
+    }
+}
+
+/// Rebuilds a tree bottom up, see [`Node::fold`].
+///
+/// Children are folded before their parent and siblings in document order, so each method
+/// receives a node whose descendants are already final. Each method returns the nodes to put in
+/// its place: none to remove it, one to keep or replace it, or several to splice them in. To wrap
+/// a node, return a new element containing it.
+///
+/// Passes can be chained with a tuple, `(A, B)` folds the whole tree with `A` and then folds
+/// everything `A` returned with `B`, including nodes that `A` wrapped in new elements.
+pub trait Fold {
+    /// Folds a node and all of its descendants, which is what [`Node::fold`] calls.
+    fn fold_tree(&mut self, node: Node) -> Vec<Node>
+    where
+        Self: Sized,
+    {
+        fold_node(self, node)
+    }
+
+    fn fold_element(&mut self, element: GenericElement) -> Vec<Node> {
+        vec![element.into()]
+    }
+
+    fn fold_void_element(&mut self, element: VoidElement) -> Vec<Node> {
+        vec![element.into()]
+    }
+
+    fn fold_text(&mut self, text: String) -> Vec<Node> {
+        vec![Node::Text(text)]
+    }
+
+    fn fold_raw_text(&mut self, text: String) -> Vec<Node> {
+        vec![Node::RawText(text)]
+    }
+
+    fn fold_comment(&mut self, text: String) -> Vec<Node> {
+        vec![Node::Comment(text)]
+    }
+
+    fn fold_doctype(&mut self, doctype: String) -> Vec<Node> {
+        vec![Node::Doctype(doctype)]
+    }
+}
+
+impl<A: Fold, B: Fold> Fold for (A, B) {
+    fn fold_tree(&mut self, node: Node) -> Vec<Node> {
+        self.0
+            .fold_tree(node)
+            .into_iter()
+            .flat_map(|node| self.1.fold_tree(node))
+            .collect()
+    }
+
+    fn fold_element(&mut self, element: GenericElement) -> Vec<Node> {
+        chain(self, Node::Element(element))
+    }
+
+    fn fold_void_element(&mut self, element: VoidElement) -> Vec<Node> {
+        chain(self, Node::VoidElement(element))
+    }
+
+    fn fold_text(&mut self, text: String) -> Vec<Node> {
+        chain(self, Node::Text(text))
+    }
+
+    fn fold_raw_text(&mut self, text: String) -> Vec<Node> {
+        chain(self, Node::RawText(text))
+    }
+
+    fn fold_comment(&mut self, text: String) -> Vec<Node> {
+        chain(self, Node::Comment(text))
+    }
+
+    fn fold_doctype(&mut self, doctype: String) -> Vec<Node> {
+        chain(self, Node::Doctype(doctype))
+    }
+}
+
+/// Folds a single node with `A`, whose children are already final, then everything it returned
+/// with `B`.
+fn chain(folders: &mut (impl Fold, impl Fold), node: Node) -> Vec<Node> {
+    fold_one(&mut folders.0, node)
+        .into_iter()
+        .flat_map(|node| folders.1.fold_tree(node))
+        .collect()
+}
+
+/// Folds a single node without descending into its children, except for fragments which are
+/// replaced by their folded nodes.
+fn fold_one(folder: &mut impl Fold, node: Node) -> Vec<Node> {
+    match node {
+        Node::Element(element) => folder.fold_element(element),
+        Node::VoidElement(element) => folder.fold_void_element(element),
+        Node::Text(text) => folder.fold_text(text),
+        Node::RawText(text) => folder.fold_raw_text(text),
+        Node::Comment(text) => folder.fold_comment(text),
+        Node::Doctype(doctype) => folder.fold_doctype(doctype),
+        Node::Fragment(nodes) => nodes
+            .into_iter()
+            .flat_map(|node| fold_node(folder, node))
+            .collect(),
+    }
+}
+
+fn fold_node(folder: &mut impl Fold, node: Node) -> Vec<Node> {
+    match node {
+        Node::Element(mut element) => {
+            element.children = std::mem::take(&mut element.children)
+                .into_iter()
+                .flat_map(|child| fold_node(folder, child))
+                .collect();
+            folder.fold_element(element)
+        }
+        node => fold_one(folder, node),
+    }
+}
+
+fn visit_node(visitor: &mut impl Visitor, node: &Node) {
+    if visitor.enter(node) {
+        for child in node.children() {
+            visit_node(visitor, child);
+        }
+    }
+    visitor.leave(node);
+}
+
+impl Node {
+    /// Walks the tree with a [`Visitor`], which can be a closure called on every node.
+    pub fn visit(&self, visitor: &mut impl Visitor) {
+        visit_node(visitor, self);
+    }
+
+    /// Rebuilds the tree with a [`Fold`], returning the nodes that replace this one.
+    pub fn fold(self, folder: &mut impl Fold) -> Vec<Node> {
+        folder.fold_tree(self)
+    }
+}
+
+/// Adds `rel="noopener noreferrer"` to links to other sites, keeping any existing `rel` values.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoOpener;
+
+impl Fold for NoOpener {
+    fn fold_element(&mut self, mut element: GenericElement) -> Vec<Node> {
+        if element.tag_name == "a" && element.attributes.get("href").is_some_and(is_external) {
+            Self::add_rel(&mut element.attributes);
+        }
+        vec![element.into()]
+    }
+
+    fn fold_void_element(&mut self, mut element: VoidElement) -> Vec<Node> {
+        if element.tag_name == "area" && element.attributes.get("href").is_some_and(is_external) {
+            Self::add_rel(&mut element.attributes);
+        }
+        vec![element.into()]
+    }
+}
+
+impl NoOpener {
+    fn add_rel(attributes: &mut crate::Attributes) {
+        let mut rel = attributes
+            .get("rel")
+            .unwrap_or_default()
+            .split_ascii_whitespace()
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+        for value in ["noopener", "noreferrer"] {
+            if !rel.iter().any(|existing| existing == value) {
+                rel.push(value.to_string());
+            }
+        }
+        attributes.set("rel".to_string(), rel.join(" "));
+    }
+}
+
+fn is_external(url: &str) -> bool {
+    ["http://", "https://", "//"]
+        .iter()
+        .any(|prefix| url.starts_with(prefix))
+}
+
+const URL_ATTRIBUTES: &[&str] = &[
+    "href",
+    "src",
+    "action",
+    "formaction",
+    "poster",
+    "cite",
+    "hx-get",
+    "hx-post",
+    "hx-put",
+    "hx-patch",
+    "hx-delete",
+];
+
+/// Rewrites relative URLs in attributes like `href` and `src` to absolute ones.
+#[derive(Debug, Clone)]
+pub struct AbsoluteUrls {
+    base: String,
+}
+
+impl AbsoluteUrls {
+    /// `base` is the absolute URL of the document, e.g. `https://example.com/blog/`.
+    pub fn new(base: impl Into<String>) -> Self {
+        Self { base: base.into() }
+    }
+
+    fn rewrite(&self, attributes: &mut crate::Attributes) {
+        for key in URL_ATTRIBUTES {
+            if let Some(url) = attributes.get(key) {
+                let url = resolve(&self.base, url);
+                attributes.set(key.to_string(), url);
+            }
+        }
+    }
+}
+
+impl Fold for AbsoluteUrls {
+    fn fold_element(&mut self, mut element: GenericElement) -> Vec<Node> {
+        self.rewrite(&mut element.attributes);
+        vec![element.into()]
+    }
+
+    fn fold_void_element(&mut self, mut element: VoidElement) -> Vec<Node> {
+        self.rewrite(&mut element.attributes);
+        vec![element.into()]
+    }
+}
+
+/// Resolves `url` against an absolute `base` URL.
+fn resolve(base: &str, url: &str) -> String {
+    let has_scheme = url.split_once(':').is_some_and(|(scheme, _)| {
+        !scheme.is_empty()
+            && scheme
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+    });
+    if url.is_empty() || url.starts_with('#') || has_scheme {
+        return url.to_string();
+    }
+
+    let (scheme, rest) = base.split_once("://").unwrap_or(("https", base));
+    if let Some(url) = url.strip_prefix("//") {
+        return format!("{scheme}://{url}");
+    }
+    let (authority, base_path) = match rest.find('/') {
+        Some(index) => rest.split_at(index),
+        None => (rest, "/"),
+    };
+    let base_path = &base_path[..base_path.find(['?', '#']).unwrap_or(base_path.len())];
+
+    let (path, suffix) = url.split_at(url.find(['?', '#']).unwrap_or(url.len()));
+    let path = if path.is_empty() {
+        base_path.to_string()
+    } else if path.starts_with('/') {
+        path.to_string()
+    } else {
+        let directory = &base_path[..base_path.rfind('/').map_or(0, |index| index + 1)];
+        format!("{directory}{path}")
+    };
+
+    let mut segments = Vec::new();
+    let mut parts = path.split('/').skip(1).peekable();
+    while let Some(segment) = parts.next() {
+        let last = parts.peek().is_none();
+        match segment {
+            "." => {}
+            ".." => {
+                segments.pop();
+            }
+            segment => segments.push(segment),
+        }
+        if last && matches!(segment, "." | "..") {
+            segments.push("");
+        }
+    }
+
+    format!("{scheme}://{authority}/{}{suffix}", segments.join("/"))
+}
+
+/// Removes all `hx-*` and `data-hx-*` attributes, e.g. to render a version without JavaScript.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StripHtmx;
+
+impl StripHtmx {
+    fn strip(attributes: &mut crate::Attributes) {
+        attributes
+            .0
+            .retain(|key, _| !key.starts_with("hx-") && !key.starts_with("data-hx-"));
+    }
+}
+
+impl Fold for StripHtmx {
+    fn fold_element(&mut self, mut element: GenericElement) -> Vec<Node> {
+        Self::strip(&mut element.attributes);
+        vec![element.into()]
+    }
+
+    fn fold_void_element(&mut self, mut element: VoidElement) -> Vec<Node> {
+        Self::strip(&mut element.attributes);
+        vec![element.into()]
+    }
+}
+
+/// Adds `loading="lazy"` to every `img` and `iframe` that doesn't set `loading` already.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LazyLoad;
+
+impl Fold for LazyLoad {
+    fn fold_element(&mut self, mut element: GenericElement) -> Vec<Node> {
+        if element.tag_name == "iframe" && element.attributes.get("loading").is_none() {
+            element
+                .attributes
+                .set("loading".to_string(), "lazy".to_string());
+        }
+        vec![element.into()]
+    }
+
+    fn fold_void_element(&mut self, mut element: VoidElement) -> Vec<Node> {
+        if element.tag_name == "img" && element.attributes.get("loading").is_none() {
+            element
+                .attributes
+                .set("loading".to_string(), "lazy".to_string());
+        }
+        vec![element.into()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{a, div, p, Area, Children, Element};
+
+    #[test]
+    fn no_opener_merges_rel() {
+        let tree: Node = div()
+            .child(a("https://example.com").attribute("rel", "external"))
+            .child(
+                Area::new_empty()
+                    .href("https://example.com")
+                    .attribute("rel", "help"),
+            )
+            .child(
+                Area::new_empty()
+                    .href("local.html")
+                    .attribute("rel", "help"),
+            )
+            .into();
+        let html = Node::Fragment(tree.fold(&mut NoOpener)).to_string();
+        assert!(html.contains(r#"rel="external noopener noreferrer""#));
+        assert!(html.contains(r#"rel="help noopener noreferrer""#));
+        assert_eq!(html.matches("noopener").count(), 2);
+    }
+
+    /// Wraps every `p` in a `div`.
+    struct WrapP;
+
+    impl Fold for WrapP {
+        fn fold_element(&mut self, element: GenericElement) -> Vec<Node> {
+            if element.tag_name == "p" {
+                return vec![div().child(element).into()];
+            }
+            vec![element.into()]
+        }
+    }
+
+    #[test]
+    fn chained_passes_reach_wrapped_descendants() {
+        let tree = || {
+            Node::from(
+                div()
+                    .attribute("hx-get", "list")
+                    .child(p("").attribute("hx-get", "items")),
+            )
+        };
+
+        let chained = Node::Fragment(tree().fold(&mut (WrapP, StripHtmx))).to_string();
+        assert_eq!(chained, "<div><div><p></p></div></div>");
+
+        let html = Node::Fragment(tree().fold(&mut (StripHtmx, (WrapP, LazyLoad)))).to_string();
+        assert_eq!(html, chained);
+
+        let wrapped = tree().fold(&mut (WrapP, WrapP));
+        assert_eq!(
+            Node::Fragment(wrapped).to_string(),
+            r#"<div hx-get="list"><div><div><p hx-get="items"></p></div></div></div>"#
+        );
+    }
+}
@@ -0,0 +1,28 @@
+// `Element` is deliberately not imported: the attribute macros must work without it in scope.
+use html_builder::{custom_element, element_attribute, element_boolean_attribute};
+
+custom_element!(StarRating, "star-rating", "A rating out of five stars.");
+element_attribute!(StarRating, max, "max", "The number of stars.");
+element_boolean_attribute!(
+    StarRating,
+    readonly,
+    set_readonly,
+    "readonly",
+    "Whether the rating can be changed."
+);
+
+#[test]
+fn attributes_without_element_in_scope() {
+    assert_eq!(
+        StarRating::new().max(5).to_string(),
+        r#"<star-rating max="5"></star-rating>"#
+    );
+    assert_eq!(
+        StarRating::new().readonly().to_string(),
+        r#"<star-rating readonly="readonly"></star-rating>"#
+    );
+    assert_eq!(
+        StarRating::new().readonly().set_readonly(false).to_string(),
+        "<star-rating></star-rating>"
+    );
+}
@@ -0,0 +1,150 @@
+use html_builder::{
+    aria::Sort,
+    prelude::{td, Children, Element, Table, TableRow, Td},
+    Node,
+};
+
+#[derive(Table)]
+struct User {
+    #[table(header = "Full name", sortable, class = "name")]
+    name: String,
+    #[table(format = age_cell, sortable)]
+    age: u32,
+    #[table(skip)]
+    #[allow(dead_code)]
+    password: String,
+    email_address: String,
+}
+
+fn age_cell(age: &u32) -> Td {
+    td().class("number").child(*age)
+}
+
+fn users() -> Vec<User> {
+    vec![
+        User {
+            name: "Ada".to_string(),
+            age: 36,
+            password: "secret".to_string(),
+            email_address: "ada@example.com".to_string(),
+        },
+        User {
+            name: "<Bob>".to_string(),
+            age: 42,
+            password: "hunter2".to_string(),
+            email_address: "bob@example.com".to_string(),
+        },
+    ]
+}
+
+fn render(users: &[User]) -> Node {
+    User::table(users)
+        .sort_url("/users")
+        .sorted_by("name", Sort::Ascending)
+        .build()
+        .into()
+}
+
+fn inner(node: &Node) -> String {
+    node.children().iter().map(ToString::to_string).collect()
+}
+
+fn attribute<'a>(node: &'a Node, key: &str) -> Option<&'a str> {
+    node.attributes()?.get(key)
+}
+
+#[test]
+fn headers() {
+    let users = users();
+    let table = render(&users);
+    let headers = table.select("thead th");
+    assert_eq!(headers.len(), 3);
+
+    let labels = headers
+        .iter()
+        .map(|header| match header.select("a").first() {
+            Some(link) => inner(link),
+            None => inner(header),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(labels, ["Full name", "Age", "Email Address"]);
+    assert!(headers
+        .iter()
+        .all(|header| attribute(header, "scope") == Some("col")));
+}
+
+#[test]
+fn cells() {
+    let users = users();
+    let table = render(&users);
+    let rows = table
+        .select("tbody tr")
+        .into_iter()
+        .map(|row| row.select("td").into_iter().map(inner).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        rows,
+        [
+            ["Ada", "36", "ada@example.com"],
+            ["&lt;Bob&gt;", "42", "bob@example.com"],
+        ]
+    );
+
+    let formatted = table.select("tbody td.number");
+    assert_eq!(formatted.len(), 2);
+    assert!(table.select("td td").is_empty());
+
+    let html = table.to_string();
+    assert!(!html.contains("secret") && !html.contains("hunter2"));
+    assert!(!html.contains("Password"));
+}
+
+#[test]
+fn column_classes() {
+    let users = users();
+    let table = render(&users);
+    let classes = table
+        .select("colgroup > col")
+        .into_iter()
+        .map(|col| attribute(col, "class"))
+        .collect::<Vec<_>>();
+    assert_eq!(classes, [Some("name"), None, None]);
+}
+
+#[test]
+fn sort_links() {
+    let users = users();
+    let table = render(&users);
+    let headers = table.select("thead th");
+
+    let sort = headers
+        .iter()
+        .map(|header| attribute(header, "aria-sort"))
+        .collect::<Vec<_>>();
+    assert_eq!(sort, [Some("ascending"), Some("none"), None]);
+
+    let links = table.select("th > a");
+    let hrefs = links
+        .iter()
+        .map(|link| attribute(link, "href"))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        hrefs,
+        [
+            Some("/users?sort=name&order=desc"),
+            Some("/users?sort=age&order=asc"),
+        ]
+    );
+    for link in links {
+        assert_eq!(attribute(link, "hx-get"), attribute(link, "href"));
+        assert_eq!(attribute(link, "hx-target"), Some("closest table"));
+        assert_eq!(attribute(link, "hx-swap"), Some("outerHTML"));
+    }
+
+    let unlinked: Node = User::table(&users).build().into();
+    assert!(unlinked.select("th a").is_empty());
+    assert_eq!(
+        attribute(unlinked.select("th")[0], "aria-sort"),
+        Some("none")
+    );
+}